@@ -27,27 +27,292 @@ const USE_AS_REF: &str = "use_as_ref";
 const GET_MUT: &str = "get_mut";
 const SKIP_NEW: &str = "skip_new";
 const GETTER_LOGIC: &str = "getter_logic";
+const GETTER_LOGIC_COPY: &str = "getter_logic_copy";
+const GETTER_LOGIC_OWNED: &str = "getter_logic_owned";
 const SKIP_GETTER: &str = "skip_getter";
 const RETURN_TYPE: &str = "return_type";
 const COPY: &str = "copy";
 const CLONE: &str = "clone";
+const COPY_CLONE: &str = "use_clone";
+const FIELD_ACCESS_HOOK: &str = "field_access_hook";
+const NEW: &str = "new";
+const BUILDER: &str = "builder";
+const GETTER_CONST_EVAL: &str = "getter_const_eval";
+const GETTER_METHOD: &str = "getter_method";
+const GETTER_INSTRUMENT: &str = "getter_instrument";
+const MAP_ITER: &str = "map_iter";
+const GETTER_METRICS: &str = "getter_metrics";
+const MIN_FIELDS: &str = "min_fields";
+const CONST_ASSERT_NON_EMPTY: &str = "const_assert_non_empty";
+const GETTER_MEMOIZE: &str = "getter_memoize";
+const PARSED_CACHE: &str = "parsed_cache";
+const GETTER_FALLIBLE: &str = "getter_fallible";
+const GETTER_CONST_NAME: &str = "getter_const_name";
+const CONST_FIELD_NAMES: &str = "const_field_names";
+const DURATION_PARTS: &str = "duration_parts";
+const GETTER_PIPE: &str = "getter_pipe";
+const GETTER_DOC_EXAMPLE: &str = "getter_doc_example";
+const COW_IF: &str = "cow_if";
+const NEW_NAME: &str = "new_name";
+const BORROW_IMPL: &str = "borrow_impl";
+const GETTER_TYPE_ALIAS: &str = "getter_type_alias";
+const FORWARD_ATTRS: &str = "forward_attrs";
+const GETTER_SUPPRESS_WARNINGS: &str = "getter_suppress_warnings";
+const VALIDATE: &str = "validate";
+const GETTER_VISIBILITY_RULE: &str = "getter_visibility_rule";
+const STABLE_HASH: &str = "stable_hash";
+const GETTER_SAFETY: &str = "getter_safety";
+const SAFE_GETTER: &str = "safe_getter";
+const ITER_CLONED: &str = "iter_cloned";
+const GETTER_SEND_BOUND: &str = "getter_send_bound";
+const NO_SEND_BOUND: &str = "no_send_bound";
+const SET_IF_NONE: &str = "set_if_none";
+const GETTER_SYNC_BOUND: &str = "getter_sync_bound";
+const FIELD_ENUM: &str = "field_enum";
+const DERIVE_TRY_FROM: &str = "derive_try_from";
+const FROM_ENV: &str = "from_env";
+const ENV: &str = "env";
+const GETTER_WHERE_CLAUSE: &str = "getter_where_clause";
+const SORTED_VIEW: &str = "sorted_view";
+const GETTER_EXCLUSIVE: &str = "getter_exclusive";
+const IMPL_ITERATOR: &str = "impl_iterator";
+const GETTER_VERSION: &str = "getter_version";
+const GETTER_DEPRECATED_SINCE: &str = "getter_deprecated_since";
+const READ_GETTER: &str = "read_getter";
+const GETTER_ATOMIC: &str = "getter_atomic";
+const NEW_DEFAULT: &str = "new_default";
+const GETTER_SELF_REF: &str = "getter_self_ref";
+const CHECKSUM: &str = "checksum";
+const GETTER_MAP_RESULT: &str = "getter_map_result";
+const DEBUG_CHECKED: &str = "debug_checked";
+const FREE_NEW: &str = "free_new";
+const FORCE: &str = "force";
+const RANGE: &str = "range";
+const FORMAT: &str = "format";
+const STRUCT_OF_ARRAYS_VIEW: &str = "struct_of_arrays_view";
+const SHARED_CLONE: &str = "shared_clone";
+const ASYNC_NEW: &str = "async_new";
+const FLAG: &str = "flag";
+const IMPL_HASH_BY: &str = "impl_hash_by";
+const CLAMP: &str = "clamp";
+const AUTO_NOW: &str = "auto_now";
+const EXPECT_SOME: &str = "expect_some";
+const UPDATE: &str = "update";
+const DOWNCAST: &str = "downcast";
+const BUILDER_VALIDATE: &str = "builder_validate";
+const PROJECT_VEC: &str = "project_vec";
+const AUTO_ID: &str = "auto_id";
+const READONLY_PROXY: &str = "readonly_proxy";
+const FIRST: &str = "first";
+const LAST: &str = "last";
+const SEMVER: &str = "semver";
+const NEWTYPE_OPS: &str = "newtype_ops";
+const RFC3339: &str = "rfc3339";
+const FIELD_COUNT: &str = "field_count";
+const SPLIT_GETTER: &str = "split_getter";
+const GETTERS: &str = "getters";
+const UNIQUE: &str = "unique";
+const GETTER_NAME: &str = "getter_name";
+const GETTER_VIS: &str = "getter_vis";
+const ORD_BY: &str = "ord_by";
+const VIS: &str = "vis";
+const RATIO: &str = "ratio";
+const RAW_PARTS: &str = "raw_parts";
+const SKIP_ALL: &str = "skip_all";
+const INCLUDE_GETTER: &str = "include_getter";
+const SET: &str = "set";
+const SKIP_SET: &str = "skip_set";
+const AS_ENUM: &str = "as_enum";
+const GET_OPTION: &str = "get_option";
+const INTO_ARG: &str = "into_arg";
+const GET_SLICE: &str = "get_slice";
+const HEAP_SIZE: &str = "heap_size";
+const RENAME: &str = "rename";
+const IMPL_PARTIAL_EQ_BY: &str = "impl_partial_eq_by";
 
 /// A procedural macro to automatically derive getter methods for struct fields.
 ///
 /// Attributes:
 /// - `use_deref`: Generate a getter method that dereferences the field.
 /// - `use_as_deref`: Generate a getter method using `AsRef` trait.
-/// - `use_as_ref`: Generate a getter method using `AsRef` trait.
+/// - `use_as_ref`: Generate a getter method using `AsRef` trait, returning `&str` for `String` and `Box<str>`, `&Path`
+///   for `PathBuf`, `&[T]` for `Vec<T>` and so on; other types need a `return_type`.
+/// - `get_option`: Return `Option<&T>`: `self.field.as_ref()` for an `Option<T>` field, `Some(&self.field)` otherwise.
+/// - `get_slice`: For a `Vec<T>` or `[T; N]` field, return `&[T]` instead of `&Vec<T>` or `&[T; N]`.
 /// - `get_mut`: Generate a mutable getter method for the field.
-/// - `skip_new`: Skip generating a `new` method for the struct.
-/// - `getter_logic`: Specify custom logic for a getter method. (MUST be a function path) !!!Use with `return_type` only
+/// - `validate`: Check the field with the given `fn(&T) -> bool` in `new`, which panics on failure, and generate a
+///   `try_new` returning `Err` naming the first invalid field instead.
+/// - `auto_now`: Leave a `SystemTime` or `Instant` field out of the `new` arguments and set it to `now()`.
+/// - `builder_validate`: Make the builder's `with_<field>` setter check the value with the given `fn(&T) -> bool`
+///   and return `Result<Self, &'static str>` naming the field on failure.
+/// - `auto_id`: Leave an integer field out of the `new` arguments and assign it the next value, starting at 1,
+///   of a per-struct static counter.
+/// - `range`: Check the field against the given range, e.g. `"0..=100"`, in `new` and `try_new` like `validate`.
+/// - `into_arg`: Take the field's `new` argument as `impl Into<T>` and convert it, e.g. to pass `&str` for a `String`.
+/// - `skip_new`: Skip generating a `new` method for the struct. Structs whose last field is `[T]`, `str` or `dyn Trait`
+///   never get one.
+/// - `new_default`: Leave the field out of the `new` arguments and initialize it by calling the given function or
+///   closure, e.g. `#[new_default = "HashMap::new"]` or `#[new_default = "|| 8080"]`.
+/// - `getter_name` / `rename`: Name the getter (and `<name>_mut`) differently from the field, e.g. `#[rename = "kind"]`
+///   for a field named `type_`. The struct-level `prefix` and `suffix` still apply.
+/// - `getter_logic`: Return the result of the given function (MUST be a function path) called with `&self.field`.
+///   The getter returns the field type unless `return_type` says otherwise; when `return_type` is a reference,
+///   the result borrows `self` through an explicit lifetime, unless it names a lifetime of the struct.
+/// - `getter_logic_copy`: Like `getter_logic`, but for a `Copy` field, which is passed to the function by value.
+/// - `getter_logic_owned`: Pass the field to the `getter_logic` function by value instead of by reference.
+/// - `getter_memoize(key_type = "K")`: Turn a `getter_logic` getter into `fn field(&self, key: K)` that calls `logic(&self.field, key)`
+///   once per key and caches the (cloned) result in a `<field>_cache: RefCell<HashMap<K, ReturnType>>` field declared on the struct.
+///   `K` must be `Clone`, as the key is passed to `logic` and also stored in the cache.
+/// - `parsed_cache(into = "cache", as = "T")`: Generate `fn cache(&self) -> &T` that parses the field with `FromStr` on first
+///   access and stores the result in the `cache: OnceCell<T>` field declared on the struct (mark it `skip_getter`).
+/// - `getter_fallible`: Also generate `try_<field>(&self) -> Option<..>` wrapping what the getter would return in `Some`,
+///   as a uniform `Option`-returning entry point. It follows `getter_name` and the struct-level `prefix`/`suffix`
+///   (e.g. `try_get_port`), and works together with `skip_getter` and `getter_logic`.
+/// - `debug_checked`: In debug builds, assert the given `Fn(&T) -> bool` invariant, e.g. `"|v| !v.is_empty()"`,
+///   before returning; release builds get the plain getter.
+/// - `force`: For a `once_cell` `Lazy<T>` field, return `&T`, forcing initialization on first access
+///   (requires the `once_cell` feature).
+/// - `shared_clone`: For an `Arc<T>` or `Rc<T>` field, also generate `fn <field>_shared(&self)` returning a clone of the
+///   pointer; `#[shared_clone(feature = "cloneable")]` only emits it when that feature of your crate is enabled.
+/// - `flag(name = "active", bit = 0)`: Generate `fn active(&self) -> bool` testing the given bit of an integer field.
+///   Repeat the attribute for every flag.
+/// - `expect_some`: For an `Option<T>` field, return `&T`, panicking with the given message (at the caller's
+///   location) when the field is `None`.
+/// - `set`: Generate `fn set_<field>(&mut self, value: T)` assigning the field.
+/// - `skip_set`: Do not generate a setter for this field despite `#[getters(set_all)]`.
+/// - `update`: Generate `fn update_<field>(&mut self, f: impl FnOnce(T) -> T)` replacing the field with `f` applied
+///   to its old value, which is moved out with `mem::take` (the field must be `Default`, as `Option` is).
+/// - `downcast`: For a `Box<dyn Any>` field, return `Option<&T>` for the given type, e.g. `#[downcast = "Config"]`.
+/// - `as_enum`: Also generate a getter named after the given enum, converting the field with `TryFrom`,
+///   e.g. `#[as_enum = "Status"]` on `code: u8` gives `fn status(&self) -> Result<Status, <Status as TryFrom<u8>>::Error>`.
+/// - `clamp`: Return the field clamped to the given inclusive range, e.g. `#[clamp = "0..=255"]`.
+/// - `project_vec`: For a `Vec<Item>` field, also generate `fn <field>_<name>s(&self)` collecting the elements mapped
+///   through the given function into the `return_type`, e.g. `#[project_vec = "get_id"]` with
+///   `#[return_type = "Vec<u64>"]` gives `items_ids()`; the plain getter keeps its default return type.
+/// - `raw_parts`: For a `Vec<T>` field, also generate `as_ptr(&self) -> *const T`, `len(&self)` and `is_empty(&self)`
+///   on the struct, e.g. to hand the buffer to C. At most one field per struct can use it.
+/// - `heap_size`: For a `Vec<T>` or `String` field, also generate `fn <field>_heap_size(&self) -> usize` estimating
+///   the bytes it allocated as its capacity times the element size (nested allocations are not counted).
+/// - `unique`: For a `Vec<T>` field, also generate `fn <field>_unique(&self) -> Vec<T>` returning the elements without
+///   duplicates, in first-seen order.
+/// - `first` / `last`: For a `Vec<T>`, array or slice field, also generate `fn <field>_first(&self) -> Option<&T>`
+///   or `<field>_last`.
+/// - `semver`: For a string field holding a semantic version, also generate `<field>_major()`, `<field>_minor()` and
+///   `<field>_patch()` returning `u64`, panicking if the version does not parse (requires the `semver` feature).
+/// - `rfc3339`: For a `SystemTime` field, or a chrono `DateTime` with the `chrono` feature, also generate
+///   `fn <field>_rfc3339(&self) -> String`; `SystemTime`s are formatted in UTC with second precision.
+/// - `format`: Generate `fn <field>_fmt(&self) -> String` formatting the field with the given spec, e.g. `"{:.2}"`.
+/// - `getter_map_result`: Return `transform(&self.field)` for a `transform` function producing a `Result`,
+///   e.g. `#[getter_map_result = "parse_port"]` with `#[return_type = "Result<u16, MyError>"]`.
+/// - `getter_pipe`: Pass `&self.field` through a comma-separated list of functions, left to right, e.g. `"a, b"` gives `b(a(&self.field))`.
+///   Requires `return_type`.
+/// - `cow_if(predicate = "p", owned = "f")`: Return `Cow<'_, Target>` borrowing the dereferenced field, or `Cow::Owned(f(&self.field))`
+///   when `p(&self.field)` is true.
+/// - `iter_cloned`: For `Vec<T>` fields, return `impl Iterator<Item = T>` yielding clones of the elements.
+/// - `read_getter`: For `RwLock<T>` or `Arc<RwLock<T>>` fields, return the read guard and also generate `<field>_write()`,
+///   `<field>_try_read()` and `<field>_try_write()`. Panics if the lock is poisoned.
+/// - `getter_atomic`: For `AtomicU32`, `AtomicBool`, etc. fields, return the loaded primitive using `Ordering::Relaxed`,
+///   or the ordering given with `#[getter_atomic(ordering = "SeqCst")]`.
+/// - `getter_method`: Call the named method on the field, e.g. `"to_uppercase"`. Returns the field type unless `return_type` is set.
+/// - `getter_doc_example`: Add the given code as a `# Examples` doctest to the getter's documentation.
+/// - `getter_type_alias`: Generate `pub type <Alias><'a, ..> = &'a FieldType;` next to the struct, carrying the struct's
+///   generic parameters used by the field type.
+/// - `forward_attrs(doc, deprecated, ..)`: Copy the field's attributes with the listed names onto the getter. `cfg_attr`
+///   is resolved by the compiler before the derive runs, so conditional attributes are forwarded in their resolved form.
+/// - `getter_version`: Document the version the getter was introduced in.
+/// - `getter_deprecated_since`: Mark the getter `#[deprecated(since = "..")]`.
+/// - `getter_instrument`: Wrap the getter in a `tracing::instrument` span named `<field>_getter` (requires the `tracing` feature).
+/// - `duration_parts`: For `Duration` fields, also generate `<field>_secs() -> u64` and `<field>_millis() -> u128`.
+/// - `stable_hash`: Also generate `<field>_hash() -> u64` hashing the field with `DefaultHasher`, or with the `Default`
+///   hasher given as `#[stable_hash = "path::to::Hasher"]`.
+/// - `set_if_none`: For `Option<T>` fields, also generate `<field>_set_if_none(&mut self, value: T) -> &T` that only
+///   stores `value` if the field is `None`.
+/// - `sorted_view`: For `Vec<T>` fields with `T: Ord + Clone`, also generate `<field>_sorted() -> Vec<T>` returning a
+///   sorted copy.
+/// - `getter_exclusive`: In debug builds, panic if the getter is re-entered on the same thread while it is running.
+/// - `getter_metrics`: Increment a `metrics` counter named `<struct>.<field>.access_count` on every call (requires the `metrics` feature).
+/// - `map_iter`: For `HashMap`/`BTreeMap` fields, also generate `<field>_keys()` and `<field>_values()` iterators.
 /// - `skip_getter`: Do not generate a getter method for this field.
-/// - `return_type`: Overrides the default return type of the getter.
+/// - `skip_all`: Struct-level, skip the getters of every field except those marked `include_getter`.
+/// - `return_type`: Overrides the default return type of the getter. Any `syn::Type` is accepted, including `impl Trait`
+///   such as `"impl Iterator<Item = u32> + '_"` or `"impl Fn(u32) -> u32"`.
 /// - `copy`: copy value in place, use for Copy types
-/// - `copy`: clone value, use for Clone types
+/// - `clone`: clone value, use for Clone types
+/// - `use_clone`: Same as `clone`, returning an owned clone of the field (or the `return_type`). Cannot be combined with `copy`.
+/// - `Box<Option<T>>` fields without a `return_type` get a getter returning `Option<&T>`.
+/// - `Cow<'a, T>` fields without a `return_type` get a getter returning `&T`.
+/// - `&'a mut T` fields get a getter returning `&T` and a `get_mut` getter reborrowing `&mut T`.
+/// - `borrow_impl`: Implement `Borrow<FieldType>` for the struct through this field, or `Borrow<T>` with `#[borrow_impl = "T"]`
+///   (e.g. `"str"` on a `String` field). Each borrowed type may only be used once per struct.
+/// - `getter_const_eval`: Generate a `const fn` getter returning the field by value. Requires `copy`, and cannot
+///   be combined with `field_access_hook`, `getter_metrics`, `getter_exclusive` or `debug_checked`.
+/// - `builder`: Struct-level, generate a `builder()` method returning a `<Struct>Builder` with `with_<field>` setters and a `build()` method.
+/// - `new`: Struct-level, keep generating `new` alongside `builder` (without it, `builder` replaces `new`).
+/// - `getter_const_name`: Generate a `pub const <NAME>: &str` holding the field name.
+/// - `const_field_names`: Struct-level, generate such a constant, named after the upper-cased field, for every field.
+/// - `min_fields`: Struct-level, fail to compile if the struct has fewer fields than the given number.
+/// - `const_assert_non_empty`: Struct-level, shorthand for `#[min_fields = 1]`.
+/// - `new_name`: Struct-level, rename the generated `new` constructor, e.g. to avoid clashing with other derives.
+///   `new` is skipped when a `bon::Builder` derive is detected, unless `new` or `new_name` is given. The derive must
+///   be written as `bon::Builder` (a bare `Builder` is left alone, as it may come from another crate) and listed in a
+///   separate `#[derive(..)]` attribute after the one containing `Getters`, as only those are visible to the macro;
+///   with `#[derive(Getters, bon::Builder)]`, add `skip_new` instead.
+/// - `getter_suppress_warnings`: Struct-level, allow `clippy::all` and `unused` lints on the generated methods.
+/// - `getter_visibility_rule`: Struct-level, choose the getters' visibility: `"always_pub"` (default), `"inherit_field"`
+///   (same as the field) or `"inherit_struct"` (same as the struct).
+/// - `getter_vis`: Set the visibility of the field's getters, e.g. `#[getter_vis = "pub(crate)"]`, or `""` for private.
+///   On the struct, sets the default for every field, taking precedence over `getter_visibility_rule`, and the
+///   visibility of `new`. `#[vis = ".."]` on a field and `#[getters(vis = "..")]` on the struct are equivalent.
+/// - `getter_safety`: Struct-level, `#[getter_safety = "unsafe"]` generates every getter as an `unsafe fn`;
+///   `"safe"` is the default.
+/// - `safe_getter`: Keep this field's getters safe despite `getter_safety = "unsafe"`.
+/// - `getter_send_bound`: Struct-level, add `where FieldType: Send` to every getter so non-`Send` fields fail to compile.
+/// - `no_send_bound`: Opt this field out of `getter_send_bound`.
+/// - `getter_sync_bound`: Struct-level, add `where FieldType: Sync` to every getter. Combines with `getter_send_bound`.
+/// - `field_enum`: Struct-level, generate a `<Struct>Field` enum with one variant per named field and a `name()` method.
+/// - `derive_try_from`: Struct-level, implement `TryFrom<Source>` for a source type with the same field names, running
+///   the `validate` functions and returning the same `&'static str` errors as `try_new`.
+/// - `from_env`: Struct-level, generate `from_env()` reading every field with `FromStr` from the environment variable
+///   `<PREFIX><FIELD>` (upper-cased), e.g. `#[from_env = "APP_"]`.
+/// - `env`: Override the environment variable read by `from_env` for this field.
+/// - `getter_where_clause`: Struct-level, append the given predicates, e.g. `"T: Clone + Send"`, to the `where` clause
+///   of the generated `impl` block.
+/// - `impl_iterator(item = "T")`: Struct-level, implement `Iterator<Item = T>` by delegating `next()` to the struct's
+///   only field, or to the one named with `field = "inner"`.
+/// - `field_access_hook`: Struct-level, call the given `fn(&'static str, &'static str)` with the struct and field name on every getter access.
+/// - `getter_self_ref`: Struct-level, generate `fn self_ref(&self) -> &Self`, or a method with the name given
+///   by `#[getter_self_ref(getter_name = "builder")]`.
+/// - `async_new`: Struct-level, make `new` (and `try_new`) an `async fn` that awaits the given
+///   `async fn(Self) -> Self` on the constructed value, e.g. `#[async_new = "init"]`.
+/// - `free_new`: Struct-level, also generate a free function with the given name taking the same arguments as `new`,
+///   e.g. `#[free_new = "make_foo"]`. It follows `new` in being `async` with `async_new` and skipped for structs whose
+///   last field is unsized.
+/// - `struct_of_arrays_view`: Struct-level, for a struct whose fields are all `Vec`s, generate a `<Struct>Row` struct
+///   borrowing one element of each and `fn row(&self, index: usize) -> Option<<Struct>Row>`.
+/// - `impl_hash_by`: Struct-level, implement `Hash` over the listed fields only, e.g. `#[impl_hash_by = "id"]`.
+/// - `impl_partial_eq_by`: Struct-level, implement `PartialEq` and `Eq` comparing the listed fields only; use the same
+///   fields as `impl_hash_by` so that equal values hash equally.
+/// - `readonly_proxy`: Struct-level, generate a `<Struct>ReadOnly<'a>` view holding `&'a Struct` that only exposes
+///   the getters, and `fn as_readonly(&self)` creating it.
+/// - `newtype_ops`: Struct-level, for a single-field newtype such as `struct Meters(f64)`, implement `Add`, `Sub`,
+///   `Mul` and `Div` on the inner value, wrapping the result.
+/// - `field_count`: Struct-level, generate `pub const FIELD_COUNT: usize` holding the number of fields. Enums are
+///   rejected.
+/// - `split_getter(buffer = "data", at = "split_at")`: Struct-level, generate `head()` and `tail()` returning the
+///   `buffer` field split at the index held by the `at` field, which is clamped to the buffer length.
+/// - `getters(prefix = "get_", suffix = "_value")`: Struct-level, prepend and/or append the given strings to the name of
+///   every field's getter, e.g. `get_name_value()` and `get_name_value_mut()` for `name`. Both default to empty.
+///   `#[getters(set_all)]` generates a `set` setter for every field.
+/// - `ord_by(priority, id)`: Struct-level, implement `Ord` and `PartialOrd` comparing the listed fields in order.
+///   `Eq` must be implemented consistently, e.g. with `impl_partial_eq_by` over the same fields.
+/// - `ratio(name = "completion", numerator = "done", denominator = "total")`: Struct-level, generate
+///   `fn completion(&self) -> f64` dividing the two fields, returning `0.0` for a zero denominator (`on_zero = "zero"`,
+///   the default), or `NaN` with `on_zero = "nan"`. Repeat the attribute for every ratio.
+/// - `checksum(over = "header, body", algo = "crc32")`: Struct-level, generate `fn checksum(&self) -> u32` feeding the
+///   listed `AsRef<[u8]>` fields, in order, through `crc32fast::Hasher` (requires the `crc32` feature).
 ///
 /// Example:
-/// ```rust
+/// ```rust,ignore
 /// #[derive(Getters)]
 /// struct MyStruct {
 ///     #[return_type = "String"]
@@ -55,11 +320,26 @@ const CLONE: &str = "clone";
 /// }
 /// ```
 /// This will generate:
-/// ```rust
+/// ```rust,ignore
 /// pub fn field(&self) -> String {
 ///     self.field.clone()
 /// }
 /// ```
+///
+/// On an enum, a getter is generated for every named field of its struct-like variants, returning
+/// `Option<&T>` that is `Some` only while the active variant carries the field:
+/// ```rust,ignore
+/// #[derive(Getters)]
+/// enum Shape {
+///     Circle { id: u32, radius: f64 },
+///     Square { id: u32, side: f64 },
+///     Empty,
+/// }
+/// ```
+/// This will generate `id()`, `radius()` and `side()`, where `id()` matches both variants.
+/// `skip_getter`, `skip_all`/`include_getter`, `getter_name`/`rename`, the `prefix`/`suffix`,
+/// `field_access_hook`, `getter_safety` and the `Send`/`Sync` bounds apply as they do for structs,
+/// taking the field attributes from the first variant carrying the field.
 #[proc_macro_derive(
     Getters,
     attributes(
@@ -72,156 +352,1288 @@ const CLONE: &str = "clone";
         skip_getter,
         return_type,
         copy,
-        clone
+        clone,
+        field_access_hook,
+        new,
+        builder,
+        getter_const_eval,
+        getter_method,
+        getter_instrument,
+        map_iter,
+        getter_metrics,
+        min_fields,
+        const_assert_non_empty,
+        getter_memoize,
+        parsed_cache,
+        getter_fallible,
+        getter_const_name,
+        const_field_names,
+        duration_parts,
+        getter_pipe,
+        getter_doc_example,
+        cow_if,
+        new_name,
+        borrow_impl,
+        getter_type_alias,
+        forward_attrs,
+        getter_suppress_warnings,
+        validate,
+        getter_visibility_rule,
+        stable_hash,
+        getter_safety,
+        safe_getter,
+        iter_cloned,
+        getter_send_bound,
+        no_send_bound,
+        set_if_none,
+        getter_sync_bound,
+        field_enum,
+        derive_try_from,
+        from_env,
+        env,
+        getter_where_clause,
+        sorted_view,
+        getter_exclusive,
+        impl_iterator,
+        getter_version,
+        getter_deprecated_since,
+        read_getter,
+        getter_atomic,
+        new_default,
+        getter_self_ref,
+        checksum,
+        getter_map_result,
+        debug_checked,
+        free_new,
+        force,
+        range,
+        format,
+        struct_of_arrays_view,
+        shared_clone,
+        async_new,
+        flag,
+        impl_hash_by,
+        impl_partial_eq_by,
+        clamp,
+        auto_now,
+        expect_some,
+        update,
+        downcast,
+        builder_validate,
+        project_vec,
+        auto_id,
+        readonly_proxy,
+        first,
+        last,
+        semver,
+        newtype_ops,
+        rfc3339,
+        field_count,
+        split_getter,
+        getters,
+        getter_logic_copy,
+        unique,
+        getter_logic_owned,
+        getter_name,
+        getter_vis,
+        ord_by,
+        vis,
+        ratio,
+        use_clone,
+        raw_parts,
+        skip_all,
+        include_getter,
+        set,
+        skip_set,
+        as_enum,
+        get_option,
+        into_arg,
+        get_slice,
+        heap_size,
+        rename
     )
 )]
 pub fn derive_getters_fn(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
-    let generics = &input.generics;
     let mut getters = Vec::new();
     let mut mut_getters = Vec::new();
+    let mut consts = Vec::new();
+    let mut module_items = Vec::new();
+    let mut readonly_getters = Vec::new();
 
-    // Check if `skip_new` attribute is present.
-    let mut skip_new = false;
-    for attr in &input.attrs {
-        if attr.path().is_ident(SKIP_NEW) {
-            skip_new = true;
-            break;
-        }
+    // Reject malformed field attributes up front; the code below parses them again infallibly.
+    let all_fields: Vec<&syn::Field> = match &input.data {
+        Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    if let Some(error) = all_fields
+        .iter()
+        .filter_map(|f| parse_field_attributes(&f.attrs).err())
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+    {
+        return error.to_compile_error().into();
+    }
+
+    // Parse struct-level attributes.
+    let struct_attrs = match parse_struct_attributes(&input.attrs) {
+        Ok(struct_attrs) => struct_attrs,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    // Extend the generics with the requested impl-wide bounds.
+    let mut generics = input.generics.clone();
+    if let Some(predicates) = &struct_attrs.where_clause {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates.iter().cloned());
     }
+    let generics = &generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     // Generate getters based on struct fields and attributes.
     if let Data::Struct(data_struct) = &input.data {
+        // Check the minimum field count if requested.
+        if let Some(min_fields) = struct_attrs.min_fields {
+            let field_count = data_struct.fields.len();
+            if field_count < min_fields {
+                let message = format!(
+                    "`{}` has {} field(s) but at least {} are required",
+                    name, field_count, min_fields
+                );
+                return syn::Error::new_spanned(name, message)
+                    .to_compile_error()
+                    .into();
+            }
+        }
+
         // Handle named fields.
         if let Fields::Named(fields_named) = &data_struct.fields {
+            let mut borrowed_types = Vec::new();
+            let mut raw_parts_field: Option<&Ident> = None;
             for f in fields_named.named.iter() {
                 let field_name = f.ident.as_ref().unwrap();
                 let field_ty = &f.ty;
 
                 // Parse and process attributes for each field.
-                let attrs = parse_field_attributes(&f.attrs);
+                let mut attrs = parse_field_attributes(&f.attrs).unwrap_or_default();
+                if struct_attrs.skip_all && !attrs.include_getter {
+                    attrs.skip_getter = true;
+                }
+
+                // `getter_const_eval` only makes sense for fields returned by value.
+                if attrs.const_eval && !attrs.copy {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_name,
+                            "`getter_const_eval` requires the `copy` attribute on the same field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                // Hooks, metrics, reentrancy guards and debug checks call non-`const` functions.
+                if attrs.const_eval {
+                    let conflict = [
+                        (struct_attrs.field_access_hook.is_some(), FIELD_ACCESS_HOOK),
+                        (attrs.metrics, GETTER_METRICS),
+                        (attrs.exclusive, GETTER_EXCLUSIVE),
+                        (attrs.debug_checked.is_some(), DEBUG_CHECKED),
+                    ]
+                    .into_iter()
+                    .find_map(|(enabled, attr)| enabled.then_some(attr));
+                    if let Some(attr) = conflict {
+                        getters.push(
+                            syn::Error::new_spanned(
+                                field_name,
+                                format!("`getter_const_eval` cannot be combined with `{}`", attr),
+                            )
+                            .to_compile_error(),
+                        );
+                        continue;
+                    }
+                }
+                if attrs.use_clone && attrs.copy {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_name,
+                            "`use_clone` cannot be combined with `copy` on the same field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                if attrs.pipe.is_some() && attrs.custom_return_type.is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_name,
+                            "`getter_pipe` requires the `return_type` attribute on the same field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                if attrs.map_result.is_some() && attrs.custom_return_type.is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_name,
+                            "`getter_map_result` requires the `return_type` attribute on the same field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                if attrs.project_vec.is_some() && attrs.custom_return_type.is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_name,
+                            "`project_vec` requires the `return_type` attribute on the same field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                if attrs.iter_cloned && wrapped_type(field_ty, "Vec").is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_ty,
+                            "`iter_cloned` requires a `Vec<T>` field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                let locked_ty = wrapped_type(field_ty, "RwLock").or_else(|| {
+                    wrapped_type(field_ty, "Arc").and_then(|ty| wrapped_type(ty, "RwLock"))
+                });
+                if attrs.read_getter && locked_ty.is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_ty,
+                            "`read_getter` requires a `RwLock<T>` or `Arc<RwLock<T>>` field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                let clamp_bounds = match &attrs.clamp {
+                    Some(syn::ExprRange {
+                        start: Some(start),
+                        limits: syn::RangeLimits::Closed(_),
+                        end: Some(end),
+                        ..
+                    }) => Some((start, end)),
+                    Some(range) => {
+                        getters.push(
+                            syn::Error::new_spanned(
+                                range,
+                                "`clamp` expects an inclusive range such as \"0..=255\"",
+                            )
+                            .to_compile_error(),
+                        );
+                        continue;
+                    }
+                    None => None,
+                };
+                let as_ref_target = as_ref_target(field_ty);
+                if attrs.use_as_ref && attrs.custom_return_type.is_none() && as_ref_target.is_none()
+                {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_ty,
+                            "`use_as_ref` cannot infer the target of this field, add a `return_type` such as \"&str\"",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                let base_name = match &attrs.getter_name {
+                    Some(lit) => match lit.parse::<Ident>() {
+                        Ok(ident) => ident,
+                        Err(_) => {
+                            getters.push(
+                                syn::Error::new_spanned(
+                                    lit,
+                                    "`getter_name` must be a valid identifier",
+                                )
+                                .to_compile_error(),
+                            );
+                            continue;
+                        }
+                    },
+                    None => field_name.clone(),
+                };
+                let option_ty = wrapped_type(field_ty, "Option");
+                if attrs.expect_some.is_some() && option_ty.is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_ty,
+                            "`expect_some` requires an `Option<T>` field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                let lazy_ty = wrapped_types(field_ty, "Lazy").and_then(|tys| tys.first().copied());
+                if attrs.force && (!cfg!(feature = "once_cell") || lazy_ty.is_none()) {
+                    let message = if cfg!(feature = "once_cell") {
+                        "`force` requires a `Lazy<T>` field"
+                    } else {
+                        "`force` requires the `once_cell` feature"
+                    };
+                    getters.push(syn::Error::new_spanned(field_ty, message).to_compile_error());
+                    continue;
+                }
+                let atomic_ty = atomic_primitive(field_ty);
+                if attrs.atomic_ordering.is_some() && atomic_ty.is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_ty,
+                            "`getter_atomic` requires a `std::sync::atomic` integer, bool or pointer field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+                if attrs.memoize_key.is_some() && attrs.custom_logic.is_none() {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_name,
+                            "`getter_memoize` requires the `getter_logic` attribute on the same field",
+                        )
+                        .to_compile_error(),
+                    );
+                    continue;
+                }
+
+                // Generate a constant holding the field name if requested.
+                let const_name = attrs.const_name.clone().or_else(|| {
+                    struct_attrs.const_field_names.then(|| {
+                        let upper = field_name.to_string().to_uppercase();
+                        Ident::new(upper.trim_start_matches("R#"), field_name.span())
+                    })
+                });
+                if let Some(const_name) = const_name {
+                    let field_str = field_name.to_string();
+                    let field_str = field_str.trim_start_matches("r#");
+                    consts.push(quote! {
+                        pub const #const_name: &'static str = #field_str;
+                    });
+                }
+
+                let hook = field_access_hook(&struct_attrs, name, field_name);
+                let vis = getter_visibility(&struct_attrs, &input.vis, f);
+                let (unsafety, safety_doc) = getter_safety(&struct_attrs, name, attrs.safe_getter);
+                let bounds = getter_bounds(&struct_attrs, &attrs, field_ty);
 
                 // Generate getters based on parsed attributes.
-                if !attrs.skip_getter {
-                    let getter = if let Some(logic_str) = attrs.custom_logic {
-                        if let Some(custom_type) = &attrs.custom_return_type {
-                            let logic: proc_macro2::TokenStream =
-                                logic_str.parse().unwrap_or_else(|_| quote! {});
-                            quote! {
-                                pub fn #field_name(&self) -> #custom_type {
-                                    #logic(self.#field_name)
+                if !attrs.skip_getter || attrs.fallible {
+                    let constness = if attrs.const_eval {
+                        quote! { const }
+                    } else {
+                        quote! {}
+                    };
+                    // With `project_vec`, `return_type` describes the projection, not the getter.
+                    let return_type = |default: proc_macro2::TokenStream| {
+                        attrs
+                            .custom_return_type
+                            .as_ref()
+                            .filter(|_| attrs.project_vec.is_none())
+                            .map_or(default, |custom_type| quote! { #custom_type })
+                    };
+
+                    let mut getter_args = quote! {};
+                    let mut call_args = quote! {};
+                    let mut getter_generics = quote! {};
+                    let mut receiver = quote! { &self };
+                    let (getter_type, getter_body) = if let Some(logic) = &attrs.custom_logic {
+                        if let Some(key_ty) = &attrs.memoize_key {
+                            let cache = affixed_name("", field_name, "_cache");
+                            getter_args = quote! { , key: #key_ty };
+                            call_args = quote! { key };
+                            (
+                                return_type(quote! { #field_ty }),
+                                quote! {
+                                    let cached = self.#cache.borrow().get(&key).cloned();
+                                    match cached {
+                                        ::std::option::Option::Some(value) => value,
+                                        ::std::option::Option::None => {
+                                            let value = #logic(
+                                                &self.#field_name,
+                                                ::std::clone::Clone::clone(&key),
+                                            );
+                                            self.#cache
+                                                .borrow_mut()
+                                                .insert(key, ::std::clone::Clone::clone(&value));
+                                            value
+                                        }
+                                    }
+                                },
+                            )
+                        } else if let Some(syn::Type::Reference(reference)) =
+                            &attrs.custom_return_type
+                        {
+                            // Tie a borrowed result to `&self` explicitly rather than relying on elision,
+                            // unless it already names a lifetime declared on the struct.
+                            let mut reference = reference.clone();
+                            let declared = |ident: &Ident| {
+                                generics
+                                    .lifetimes()
+                                    .any(|param| param.lifetime.ident == *ident)
+                            };
+                            match &reference.lifetime {
+                                Some(lifetime) if declared(&lifetime.ident) => {}
+                                Some(lifetime) => {
+                                    getter_generics = quote! { <#lifetime> };
+                                    receiver = quote! { &#lifetime self };
+                                }
+                                None => {
+                                    let mut fresh = String::from("s");
+                                    while declared(&Ident::new(
+                                        &fresh,
+                                        proc_macro2::Span::call_site(),
+                                    )) {
+                                        fresh.push('_');
+                                    }
+                                    let lifetime = syn::Lifetime::new(
+                                        &format!("'{}", fresh),
+                                        proc_macro2::Span::call_site(),
+                                    );
+                                    getter_generics = quote! { <#lifetime> };
+                                    receiver = quote! { &#lifetime self };
+                                    reference.lifetime = Some(lifetime);
                                 }
                             }
+                            (quote! { #reference }, quote! { #logic(&self.#field_name) })
+                        } else if attrs.logic_by_value {
+                            (
+                                return_type(quote! { #field_ty }),
+                                quote! { #logic(self.#field_name) },
+                            )
                         } else {
-                            let logic: proc_macro2::TokenStream =
-                                logic_str.parse().unwrap_or_else(|_| quote! {});
+                            (
+                                return_type(quote! { #field_ty }),
+                                quote! { #logic(&self.#field_name) },
+                            )
+                        }
+                    } else if let Some(transform) = &attrs.map_result {
+                        (
+                            return_type(quote! { #field_ty }),
+                            quote! { #transform(&self.#field_name) },
+                        )
+                    } else if let Some(pipe) = &attrs.pipe {
+                        let piped = pipe
+                            .iter()
+                            .fold(quote! { &self.#field_name }, |arg, f| quote! { #f(#arg) });
+                        (return_type(quote! { #field_ty }), piped)
+                    } else if let Some((predicate, owned)) = &attrs.cow_if {
+                        (
+                            return_type(quote! {
+                                ::std::borrow::Cow<'_, <#field_ty as ::std::ops::Deref>::Target>
+                            }),
                             quote! {
-                                pub fn #field_name(&self) -> u32 {
-                                    #logic(self.#field_name)
+                                if #predicate(&self.#field_name) {
+                                    ::std::borrow::Cow::Owned(#owned(&self.#field_name))
+                                } else {
+                                    ::std::borrow::Cow::Borrowed(&*self.#field_name)
                                 }
-                            }
-                        }
+                            },
+                        )
+                    } else if let (true, Some(elem_ty)) =
+                        (attrs.iter_cloned, wrapped_type(field_ty, "Vec"))
+                    {
+                        (
+                            return_type(quote! { impl Iterator<Item = #elem_ty> + '_ }),
+                            quote! { self.#field_name.iter().cloned() },
+                        )
+                    } else if let (true, Some(locked_ty)) = (attrs.read_getter, locked_ty) {
+                        (
+                            return_type(quote! { ::std::sync::RwLockReadGuard<'_, #locked_ty> }),
+                            quote! { self.#field_name.read().unwrap() },
+                        )
+                    } else if let (Some(message), Some(inner_ty)) = (&attrs.expect_some, option_ty)
+                    {
+                        (
+                            return_type(quote! { &#inner_ty }),
+                            quote! { self.#field_name.as_ref().expect(#message) },
+                        )
+                    } else if let Some(target_ty) = &attrs.downcast {
+                        (
+                            return_type(quote! { ::std::option::Option<&#target_ty> }),
+                            quote! { self.#field_name.downcast_ref::<#target_ty>() },
+                        )
+                    } else if let Some((start, end)) = clamp_bounds {
+                        (
+                            return_type(quote! { #field_ty }),
+                            quote! { ::std::clone::Clone::clone(&self.#field_name).clamp(#start, #end) },
+                        )
+                    } else if let (true, Some(lazy_ty)) = (attrs.force, lazy_ty) {
+                        (quote! { &#lazy_ty }, quote! { &*self.#field_name })
+                    } else if let (Some(ordering), Some(atomic_ty)) =
+                        (&attrs.atomic_ordering, &atomic_ty)
+                    {
+                        (
+                            return_type(quote! { #atomic_ty }),
+                            quote! { self.#field_name.load(::std::sync::atomic::Ordering::#ordering) },
+                        )
+                    } else if let Some(method) = &attrs.custom_method {
+                        (
+                            return_type(quote! { #field_ty }),
+                            quote! { self.#field_name.#method() },
+                        )
                     } else if attrs.copy {
-                        if let Some(custom_type) = &attrs.custom_return_type {
-                            quote! {
-                                pub fn #field_name(&self) -> #custom_type {
-                                    self.#field_name
+                        (
+                            return_type(quote! { #field_ty }),
+                            quote! { self.#field_name },
+                        )
+                    } else if attrs.clone {
+                        (
+                            return_type(quote! { #field_ty }),
+                            quote! { self.#field_name.clone() },
+                        )
+                    } else if attrs.use_clone {
+                        (
+                            return_type(quote! { #field_ty }),
+                            quote! { ::std::clone::Clone::clone(&self.#field_name).into() },
+                        )
+                    } else if attrs.as_option {
+                        match option_ty {
+                            Some(inner_ty) => (
+                                return_type(quote! { ::std::option::Option<&#inner_ty> }),
+                                quote! { self.#field_name.as_ref() },
+                            ),
+                            None => (
+                                return_type(quote! { ::std::option::Option<&#field_ty> }),
+                                quote! { ::std::option::Option::Some(&self.#field_name) },
+                            ),
+                        }
+                    } else if attrs.get_slice {
+                        match element_type(field_ty) {
+                            Some(elem_ty) => (
+                                return_type(quote! { &[#elem_ty] }),
+                                quote! { &self.#field_name[..] },
+                            ),
+                            None => (
+                                return_type(quote! { &#field_ty }),
+                                syn::Error::new_spanned(
+                                    field_ty,
+                                    "`get_slice` requires a `Vec<T>`, array or slice field",
+                                )
+                                .to_compile_error(),
+                            ),
+                        }
+                    } else if attrs.use_deref {
+                        (
+                            return_type(quote! { &<#field_ty as std::ops::Deref>::Target }),
+                            quote! { &*self.#field_name },
+                        )
+                    } else if attrs.use_as_deref {
+                        (
+                            return_type(
+                                quote! { &<#field_ty as std::convert::AsDeref<#field_ty>>::Target },
+                            ),
+                            quote! { self.#field_name.as_deref() },
+                        )
+                    } else if attrs.use_as_ref {
+                        (
+                            return_type(quote! { &#as_ref_target }),
+                            quote! { ::std::convert::AsRef::as_ref(&self.#field_name) },
+                        )
+                    } else if let (None, Some(referent_ty)) =
+                        (&attrs.custom_return_type, mut_reference_type(field_ty))
+                    {
+                        // Reborrow `&'a mut T` fields as `&T`.
+                        (quote! { &#referent_ty }, quote! { &*self.#field_name })
+                    } else if let (None, Some(borrowed_ty)) =
+                        (&attrs.custom_return_type, wrapped_type(field_ty, "Cow"))
+                    {
+                        // Hide `Cow<'a, T>` behind a plain `&T`.
+                        (quote! { &#borrowed_ty }, quote! { &*self.#field_name })
+                    } else if let (None, Some(inner_ty)) = (
+                        &attrs.custom_return_type,
+                        wrapped_type(field_ty, "Box").and_then(|ty| wrapped_type(ty, "Option")),
+                    ) {
+                        // Flatten `Box<Option<T>>` into `Option<&T>`.
+                        (
+                            quote! { ::std::option::Option<&#inner_ty> },
+                            quote! { (*self.#field_name).as_ref() },
+                        )
+                    } else {
+                        (
+                            return_type(quote! { &#field_ty }),
+                            quote! { &self.#field_name },
+                        )
+                    };
+
+                    let mut getter_attrs = Vec::new();
+                    getter_attrs.extend(
+                        f.attrs
+                            .iter()
+                            .filter(|attr| {
+                                attrs
+                                    .forward_attrs
+                                    .iter()
+                                    .any(|forwarded| attr.path().is_ident(forwarded))
+                            })
+                            .map(|attr| quote! { #attr }),
+                    );
+                    if attrs.expect_some.is_some() {
+                        getter_attrs.push(quote! { #[track_caller] });
+                    }
+                    if let Some(version) = &attrs.version {
+                        let since = format!(" *Available since version {}.*", version.value());
+                        getter_attrs.push(quote! { #[doc = #since] });
+                    }
+                    if let Some(version) = &attrs.deprecated_since {
+                        getter_attrs.push(quote! { #[deprecated(since = #version)] });
+                    }
+                    if let Some(example) = &attrs.doc_example {
+                        let lines = example.value();
+                        let lines = lines.lines().map(|line| format!(" {}", line));
+                        getter_attrs.push(quote! {
+                            #[doc = " # Examples"]
+                            #[doc = " ```rust"]
+                            #(#[doc = #lines])*
+                            #[doc = " ```"]
+                        });
+                    }
+                    if cfg!(feature = "tracing") && attrs.instrument {
+                        let span_name = format!("{}_getter", field_name);
+                        getter_attrs.push(quote! {
+                            #[tracing::instrument(name = #span_name, skip(self))]
+                        });
+                    }
+
+                    let metrics = if cfg!(feature = "metrics") && attrs.metrics {
+                        let counter_name = format!("{}.{}.access_count", name, field_name);
+                        quote! { metrics::counter!(#counter_name).increment(1); }
+                    } else {
+                        quote! {}
+                    };
+
+                    let exclusive = if attrs.exclusive {
+                        let message = format!("reentrant call to `{}::{}`", name, field_name);
+                        quote! {
+                            #[cfg(debug_assertions)]
+                            let _exclusive = {
+                                thread_local! {
+                                    static IN_GETTER: ::std::cell::Cell<bool> = ::std::cell::Cell::new(false);
                                 }
-                            }
-                        } else {
-                            quote! {
-                                pub fn #field_name(&self) -> #field_ty {
-                                    self.#field_name
+                                struct Guard;
+                                impl Drop for Guard {
+                                    fn drop(&mut self) {
+                                        IN_GETTER.with(|flag| flag.set(false));
+                                    }
                                 }
+                                IN_GETTER.with(|flag| debug_assert!(!flag.replace(true), #message));
+                                Guard
+                            };
+                        }
+                    } else {
+                        quote! {}
+                    };
+
+                    // Generate a `try_` companion wrapping the getter result.
+                    if attrs.fallible {
+                        let try_name =
+                            affixed_name("try_", &getter_name(&struct_attrs, &base_name), "");
+                        getters.push(quote! {
+                            #safety_doc #vis #unsafety fn #try_name #getter_generics(#receiver #getter_args) -> ::std::option::Option<#getter_type> #bounds {
+                                #hook
+                                ::std::option::Option::Some({ #getter_body })
                             }
+                        });
+                        if struct_attrs.readonly_proxy {
+                            let call = proxy_call(&try_name, &call_args, &unsafety);
+                            readonly_getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #try_name #getter_generics(#receiver #getter_args) -> ::std::option::Option<#getter_type> #bounds {
+                                    #call
+                                }
+                            });
                         }
-                    } else if attrs.clone {
-                        if let Some(custom_type) = &attrs.custom_return_type {
-                            quote! {
-                                pub fn #field_name(&self) -> #custom_type {
-                                    self.#field_name.clone()
+                    }
+
+                    if !attrs.skip_getter {
+                        let getter_name = getter_name(&struct_attrs, &base_name);
+                        if let Some(invariant) = &attrs.debug_checked {
+                            let message = format!(
+                                "invariant `{}` violated for `{}::{}`",
+                                quote! { #invariant },
+                                name,
+                                field_name
+                            );
+                            getters.push(quote! {
+                                #(#getter_attrs)*
+                                #[cfg(debug_assertions)]
+                                #safety_doc #vis #unsafety fn #getter_name #getter_generics(#receiver #getter_args) -> #getter_type #bounds {
+                                    #hook
+                                    #metrics
+                                    #exclusive
+                                    assert!((#invariant)(&self.#field_name), "{}", #message);
+                                    #getter_body
                                 }
-                            }
+
+                                #(#getter_attrs)*
+                                #[cfg(not(debug_assertions))]
+                                #safety_doc #vis #constness #unsafety fn #getter_name #getter_generics(#receiver #getter_args) -> #getter_type #bounds {
+                                    #hook
+                                    #metrics
+                                    #getter_body
+                                }
+                            });
                         } else {
-                            quote! {
-                                pub fn #field_name(&self) -> #field_ty {
-                                    self.#field_name.clone()
+                            getters.push(quote! {
+                                #(#getter_attrs)*
+                                #safety_doc #vis #constness #unsafety fn #getter_name #getter_generics(#receiver #getter_args) -> #getter_type #bounds {
+                                    #hook
+                                    #metrics
+                                    #exclusive
+                                    #getter_body
                                 }
-                            }
+                            });
                         }
-                    } else if attrs.use_deref {
-                        if let Some(custom_type) = &attrs.custom_return_type {
+
+                        // Mirror the getter on the read-only proxy, delegating to the borrowed struct.
+                        if struct_attrs.readonly_proxy {
+                            let call = proxy_call(&getter_name, &call_args, &unsafety);
+                            readonly_getters.push(quote! {
+                                #(#getter_attrs)*
+                                #safety_doc #vis #unsafety fn #getter_name #getter_generics(#receiver #getter_args) -> #getter_type #bounds {
+                                    #call
+                                }
+                            });
+                        }
+                    }
+
+                    // Generate mutable getters if needed.
+                    if attrs.generate_mut && !attrs.skip_getter {
+                        let getter_mut_name =
+                            affixed_name("", &getter_name(&struct_attrs, &base_name), "_mut");
+                        let getter_mut = if let Some(referent_ty) = mut_reference_type(field_ty) {
+                            // Reborrow `&'a mut T` fields for the lifetime of `&mut self`.
                             quote! {
-                                pub fn #field_name(&self) -> #custom_type {
-                                    &*self.#field_name
+                                #safety_doc #vis #unsafety fn #getter_mut_name(&mut self) -> &mut #referent_ty #bounds {
+                                    #hook
+                                    &mut *self.#field_name
                                 }
                             }
                         } else {
                             quote! {
-                                pub fn #field_name(&self) -> &<#field_ty as std::ops::Deref>::Target {
-                                    &*self.#field_name
+                                #safety_doc #vis #unsafety fn #getter_mut_name(&mut self) -> &mut #field_ty #bounds {
+                                    #hook
+                                    &mut self.#field_name
                                 }
                             }
+                        };
+                        mut_getters.push(getter_mut);
+                    }
+                }
+
+                // Generate a type alias for the borrowed field.
+                if let Some(alias) = &attrs.type_alias {
+                    let vis = &input.vis;
+                    let params = generics.params.iter().filter_map(|param| match param {
+                        syn::GenericParam::Lifetime(param) => {
+                            let lifetime = &param.lifetime;
+                            mentions_ident(field_ty, &lifetime.ident).then(|| quote! { #lifetime })
                         }
-                    } else if attrs.use_as_deref {
-                        if let Some(custom_type) = &attrs.custom_return_type {
-                            quote! {
-                                pub fn #field_name(&self) -> #custom_type {
-                                    self.#field_name.as_deref()
+                        syn::GenericParam::Type(param) => {
+                            let ident = &param.ident;
+                            mentions_ident(field_ty, ident).then(|| quote! { #ident })
+                        }
+                        syn::GenericParam::Const(param) => {
+                            let ident = &param.ident;
+                            let ty = &param.ty;
+                            mentions_ident(field_ty, ident).then(|| quote! { const #ident: #ty })
+                        }
+                    });
+                    module_items.push(quote! {
+                        #vis type #alias<'getter, #(#params),*> = &'getter #field_ty;
+                    });
+                }
+
+                // Implement `Borrow` through this field.
+                if let Some(borrow_ty) = &attrs.borrow_impl {
+                    let borrow_ty = borrow_ty.as_ref().unwrap_or(field_ty);
+                    let borrow_key = quote! { #borrow_ty }.to_string();
+                    if borrowed_types.contains(&borrow_key) {
+                        module_items.push(
+                            syn::Error::new_spanned(
+                                borrow_ty,
+                                "`borrow_impl` is already used for this type on another field",
+                            )
+                            .to_compile_error(),
+                        );
+                    } else {
+                        borrowed_types.push(borrow_key);
+                        module_items.push(quote! {
+                            impl #impl_generics ::std::borrow::Borrow<#borrow_ty> for #name #ty_generics #where_clause {
+                                fn borrow(&self) -> &#borrow_ty {
+                                    &self.#field_name
                                 }
                             }
-                        } else {
-                            quote! {
-                                pub fn #field_name(&self) -> &<#field_ty as std::convert::AsDeref<#field_ty>>::Target {
-                                    self.#field_name.as_deref()
+                        });
+                    }
+                }
+
+                // Generate a lazily parsed view of the field.
+                if let Some((cache, parsed_ty)) = &attrs.parsed_cache {
+                    let message = format!("failed to parse `{}`", field_name);
+                    getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #cache(&self) -> &#parsed_ty {
+                            #hook
+                            self.#cache.get_or_init(|| {
+                                self.#field_name.parse::<#parsed_ty>().expect(#message)
+                            })
+                        }
+                    });
+                }
+
+                // Generate key and value iterators for map fields.
+                if attrs.map_iter {
+                    let map_types = wrapped_types(field_ty, "HashMap")
+                        .or_else(|| wrapped_types(field_ty, "BTreeMap"));
+                    match map_types.as_deref() {
+                        Some([key_ty, value_ty, ..]) => {
+                            let keys_name = affixed_name("", field_name, "_keys");
+                            let values_name = affixed_name("", field_name, "_values");
+                            getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #keys_name(&self) -> impl Iterator<Item = &#key_ty> + '_ {
+                                    #hook
+                                    self.#field_name.keys()
                                 }
-                            }
+
+                                #safety_doc #vis #unsafety fn #values_name(&self) -> impl Iterator<Item = &#value_ty> + '_ {
+                                    #hook
+                                    self.#field_name.values()
+                                }
+                            });
                         }
-                    } else if attrs.use_as_ref {
-                        if let Some(custom_type) = &attrs.custom_return_type {
-                            quote! {
-                                pub fn #field_name(&self) -> #custom_type {
-                                    self.#field_name.as_ref()
+                        _ => getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`map_iter` requires a `HashMap<K, V>` or `BTreeMap<K, V>` field",
+                            )
+                            .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate a setter replacing the field.
+                if (attrs.set || struct_attrs.set_all) && !attrs.skip_set {
+                    let setter_name = affixed_name("set_", field_name, "");
+                    mut_getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #setter_name(&mut self, value: #field_ty) {
+                            #hook
+                            self.#field_name = value;
+                        }
+                    });
+                }
+
+                // Generate an updater replacing the field with a function of its old value.
+                if attrs.update {
+                    let update_name = affixed_name("update_", field_name, "");
+                    mut_getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #update_name(&mut self, f: impl ::std::ops::FnOnce(#field_ty) -> #field_ty) {
+                            #hook
+                            let old = ::std::mem::take(&mut self.#field_name);
+                            self.#field_name = f(old);
+                        }
+                    });
+                }
+
+                // Generate a setter that only fills an empty `Option`.
+                if attrs.set_if_none {
+                    match wrapped_type(field_ty, "Option") {
+                        Some(inner_ty) => {
+                            let setter_name = affixed_name("", field_name, "_set_if_none");
+                            mut_getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #setter_name(&mut self, value: #inner_ty) -> &#inner_ty {
+                                    #hook
+                                    self.#field_name.get_or_insert(value)
                                 }
-                            }
-                        } else {
-                            quote! {
-                                pub fn #field_name(&self) -> &<#field_ty as std::convert::AsRef<#field_ty>>::Target {
-                                    self.#field_name.as_ref()
+                            });
+                        }
+                        None => getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`set_if_none` requires an `Option<T>` field",
+                            )
+                            .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate the write and non-blocking guards of a `RwLock` field.
+                if let (true, Some(locked_ty)) = (attrs.read_getter, locked_ty) {
+                    let write_name = affixed_name("", field_name, "_write");
+                    let try_read_name = affixed_name("", field_name, "_try_read");
+                    let try_write_name = affixed_name("", field_name, "_try_write");
+                    getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #write_name(&self) -> ::std::sync::RwLockWriteGuard<'_, #locked_ty> {
+                            #hook
+                            self.#field_name.write().unwrap()
+                        }
+
+                        #safety_doc #vis #unsafety fn #try_read_name(&self) -> ::std::option::Option<::std::sync::RwLockReadGuard<'_, #locked_ty>> {
+                            #hook
+                            self.#field_name.try_read().ok()
+                        }
+
+                        #safety_doc #vis #unsafety fn #try_write_name(&self) -> ::std::option::Option<::std::sync::RwLockWriteGuard<'_, #locked_ty>> {
+                            #hook
+                            self.#field_name.try_write().ok()
+                        }
+                    });
+                }
+
+                // Generate a sorted copy of a `Vec` field.
+                if attrs.sorted_view {
+                    match wrapped_type(field_ty, "Vec") {
+                        Some(elem_ty) => {
+                            let sorted_name = affixed_name("", field_name, "_sorted");
+                            getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #sorted_name(&self) -> ::std::vec::Vec<#elem_ty> {
+                                    #hook
+                                    let mut sorted = ::std::clone::Clone::clone(&self.#field_name);
+                                    sorted.sort();
+                                    sorted
                                 }
+                            });
+                        }
+                        None => getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`sorted_view` requires a `Vec<T>` field",
+                            )
+                            .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate a deterministic hash of the field.
+                if let Some(hasher) = &attrs.stable_hash {
+                    let hash_name = affixed_name("", field_name, "_hash");
+                    let hasher = hasher.as_ref().map_or_else(
+                        || quote! { ::std::collections::hash_map::DefaultHasher },
+                        |hasher| quote! { #hasher },
+                    );
+                    getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #hash_name(&self) -> u64 {
+                            #hook
+                            let mut hasher = <#hasher as ::std::default::Default>::default();
+                            ::std::hash::Hash::hash(&self.#field_name, &mut hasher);
+                            ::std::hash::Hasher::finish(&hasher)
+                        }
+                    });
+                }
+
+                // Generate a reference-count-bumping accessor, optionally behind a feature of the user crate.
+                if let Some(feature) = &attrs.shared_clone {
+                    if wrapped_type(field_ty, "Arc").is_some()
+                        || wrapped_type(field_ty, "Rc").is_some()
+                    {
+                        let shared_name = affixed_name("", field_name, "_shared");
+                        let cfg = feature
+                            .as_ref()
+                            .map(|feature| quote! { #[cfg(feature = #feature)] });
+                        getters.push(quote! {
+                            #cfg
+                            #safety_doc #vis #unsafety fn #shared_name(&self) -> #field_ty {
+                                #hook
+                                ::std::clone::Clone::clone(&self.#field_name)
+                            }
+                        });
+                    } else {
+                        getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`shared_clone` requires an `Arc<T>` or `Rc<T>` field",
+                            )
+                            .to_compile_error(),
+                        );
+                    }
+                }
+
+                // Generate a predicate per named bit of a flags field.
+                for (flag_name, bit) in &attrs.flags {
+                    getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #flag_name(&self) -> bool {
+                            #hook
+                            self.#field_name & (1 << #bit) != 0
+                        }
+                    });
+                }
+
+                // Generate a collection of the projected elements.
+                if let (Some(projection), Some(projected_ty)) =
+                    (&attrs.project_vec, &attrs.custom_return_type)
+                {
+                    let projection_name = projection.segments.last().unwrap().ident.to_string();
+                    let projected_name = affixed_name(
+                        "",
+                        field_name,
+                        &format!("_{}s", projection_name.trim_start_matches("get_")),
+                    );
+                    getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #projected_name(&self) -> #projected_ty {
+                            #hook
+                            self.#field_name.iter().map(#projection).collect()
+                        }
+                    });
+                }
+
+                // Generate pointer/length accessors exposing a `Vec` buffer over FFI.
+                // They are named after the struct rather than the field, so only one field may use them.
+                if let (true, Some(first)) = (attrs.raw_parts, raw_parts_field) {
+                    getters.push(
+                        syn::Error::new_spanned(
+                            field_name,
+                            format!(
+                                "`raw_parts` is already used on `{}`, only one field can generate `as_ptr`, `len` and `is_empty`",
+                                first
+                            ),
+                        )
+                        .to_compile_error(),
+                    );
+                } else if attrs.raw_parts {
+                    raw_parts_field = Some(field_name);
+                    match wrapped_type(field_ty, "Vec") {
+                        Some(elem_ty) => getters.push(quote! {
+                            #safety_doc #vis #unsafety fn as_ptr(&self) -> *const #elem_ty {
+                                #hook
+                                self.#field_name.as_ptr()
+                            }
+
+                            #safety_doc #vis #unsafety fn len(&self) -> usize {
+                                #hook
+                                self.#field_name.len()
                             }
+
+                            #safety_doc #vis #unsafety fn is_empty(&self) -> bool {
+                                #hook
+                                self.#field_name.is_empty()
+                            }
+                        }),
+                        None => getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`raw_parts` requires a `Vec<T>` field",
+                            )
+                            .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate a deduplicated copy of a `Vec` field.
+                if attrs.unique {
+                    match wrapped_type(field_ty, "Vec") {
+                        Some(elem_ty) => {
+                            let unique_name = affixed_name("", field_name, "_unique");
+                            getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #unique_name(&self) -> ::std::vec::Vec<#elem_ty>
+                                where
+                                    #elem_ty: ::std::cmp::Eq + ::std::hash::Hash + ::std::clone::Clone,
+                                {
+                                    #hook
+                                    let mut seen = ::std::collections::HashSet::new();
+                                    self.#field_name
+                                        .iter()
+                                        .filter(|item| seen.insert(*item))
+                                        .cloned()
+                                        .collect()
+                                }
+                            });
+                        }
+                        None => getters.push(
+                            syn::Error::new_spanned(field_ty, "`unique` requires a `Vec<T>` field")
+                                .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate `first`/`last` element accessors for sequence fields.
+                for (enabled, method) in [(attrs.first, "first"), (attrs.last, "last")] {
+                    if !enabled {
+                        continue;
+                    }
+                    match element_type(field_ty) {
+                        Some(elem_ty) => {
+                            let accessor_name =
+                                affixed_name("", field_name, &format!("_{}", method));
+                            let method = Ident::new(method, field_name.span());
+                            getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #accessor_name(&self) -> ::std::option::Option<&#elem_ty> {
+                                    #hook
+                                    self.#field_name.#method()
+                                }
+                            });
                         }
+                        None => getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                format!("`{}` requires a `Vec<T>`, array or slice field", method),
+                            )
+                            .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate an estimate of the heap memory held by the field.
+                if attrs.heap_size {
+                    let elem_ty = if is_type(field_ty, "String") {
+                        Some(quote! { u8 })
                     } else {
-                        #[allow(clippy::collapsible_else_if)]
-                        if let Some(custom_type) = &attrs.custom_return_type {
-                            quote! {
-                                pub fn #field_name(&self) -> #custom_type {
-                                    &self.#field_name
+                        wrapped_type(field_ty, "Vec").map(|elem_ty| quote! { #elem_ty })
+                    };
+                    match elem_ty {
+                        Some(elem_ty) => {
+                            let heap_size_name = affixed_name("", field_name, "_heap_size");
+                            getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #heap_size_name(&self) -> usize {
+                                    #hook
+                                    self.#field_name.capacity() * ::std::mem::size_of::<#elem_ty>()
                                 }
-                            }
-                        } else {
-                            quote! {
-                                pub fn #field_name(&self) -> &#field_ty {
-                                    &self.#field_name
+                            });
+                        }
+                        None => getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`heap_size` requires a `Vec<T>` or `String` field",
+                            )
+                            .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate a typed accessor converting the field into an enum.
+                if let Some(enum_path) = &attrs.as_enum {
+                    let enum_ident = &enum_path
+                        .segments
+                        .last()
+                        .expect("enum path has at least one segment")
+                        .ident;
+                    let accessor_name =
+                        Ident::new(&snake_case(&enum_ident.to_string()), enum_ident.span());
+                    getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #accessor_name(&self)
+                            -> ::std::result::Result<#enum_path, <#enum_path as ::std::convert::TryFrom<#field_ty>>::Error>
+                        {
+                            #hook
+                            <#enum_path as ::std::convert::TryFrom<#field_ty>>::try_from(::std::clone::Clone::clone(&self.#field_name))
+                        }
+                    });
+                }
+
+                // Generate semantic version component accessors.
+                if attrs.semver {
+                    if cfg!(feature = "semver") {
+                        let message = format!("`{}` is not a valid semantic version", field_name);
+                        for component in ["major", "minor", "patch"] {
+                            let accessor_name =
+                                affixed_name("", field_name, &format!("_{}", component));
+                            let component = Ident::new(component, field_name.span());
+                            getters.push(quote! {
+                                #[track_caller]
+                                #safety_doc #vis #unsafety fn #accessor_name(&self) -> u64 {
+                                    #hook
+                                    ::semver::Version::parse(::std::convert::AsRef::<str>::as_ref(&self.#field_name))
+                                        .expect(#message)
+                                        .#component
                                 }
-                            }
+                            });
                         }
+                    } else {
+                        getters.push(
+                            syn::Error::new_spanned(
+                                field_name,
+                                "`semver` requires the `semver` feature",
+                            )
+                            .to_compile_error(),
+                        );
+                    }
+                }
+
+                // Generate an RFC 3339 timestamp accessor.
+                if attrs.rfc3339 {
+                    let rfc3339_name = affixed_name("", field_name, "_rfc3339");
+                    let body = if is_type(field_ty, "SystemTime") {
+                        Some(system_time_rfc3339(quote! { self.#field_name }))
+                    } else if is_type(field_ty, "DateTime") && cfg!(feature = "chrono") {
+                        Some(quote! { self.#field_name.to_rfc3339() })
+                    } else {
+                        None
                     };
+                    match body {
+                        Some(body) => getters.push(quote! {
+                            #safety_doc #vis #unsafety fn #rfc3339_name(&self) -> ::std::string::String {
+                                #hook
+                                #body
+                            }
+                        }),
+                        None => getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`rfc3339` requires a `SystemTime` field, or a chrono `DateTime` with the `chrono` feature",
+                            )
+                            .to_compile_error(),
+                        ),
+                    }
+                }
+
+                // Generate a formatted `String` accessor.
+                if let Some(spec) = &attrs.format {
+                    match spec {
+                        syn::Lit::Str(spec) => {
+                            let fmt_name = affixed_name("", field_name, "_fmt");
+                            getters.push(quote! {
+                                #safety_doc #vis #unsafety fn #fmt_name(&self) -> ::std::string::String {
+                                    #hook
+                                    ::std::format!(#spec, self.#field_name)
+                                }
+                            });
+                        }
+                        _ => getters.push(
+                            syn::Error::new_spanned(spec, "`format` expects a string literal")
+                                .to_compile_error(),
+                        ),
+                    }
+                }
 
-                    getters.push(getter);
+                // Generate component accessors for `Duration` fields.
+                if attrs.duration_parts {
+                    if is_type(field_ty, "Duration") {
+                        let secs_name = affixed_name("", field_name, "_secs");
+                        let millis_name = affixed_name("", field_name, "_millis");
+                        getters.push(quote! {
+                            #safety_doc #vis #unsafety fn #secs_name(&self) -> u64 {
+                                #hook
+                                self.#field_name.as_secs()
+                            }
 
-                    // Generate mutable getters if needed.
-                    if attrs.generate_mut {
-                        let getter_mut_name =
-                            Ident::new(&format!("{}_mut", field_name), field_name.span());
-                        let getter_mut = quote! {
-                            pub fn #getter_mut_name(&mut self) -> &mut #field_ty {
-                                &mut self.#field_name
+                            #safety_doc #vis #unsafety fn #millis_name(&self) -> u128 {
+                                #hook
+                                self.#field_name.as_millis()
                             }
-                        };
-                        mut_getters.push(getter_mut);
+                        });
+                    } else {
+                        getters.push(
+                            syn::Error::new_spanned(
+                                field_ty,
+                                "`duration_parts` requires a `Duration` field",
+                            )
+                            .to_compile_error(),
+                        );
                     }
                 }
             }
@@ -229,83 +1641,1879 @@ pub fn derive_getters_fn(input: TokenStream) -> TokenStream {
         // Handle unnamed fields (tuples).
         if let Fields::Unnamed(fields_unnamed) = &data_struct.fields {
             for (i, f) in fields_unnamed.unnamed.iter().enumerate() {
+                if struct_attrs.skip_all
+                    && !parse_field_attributes(&f.attrs)
+                        .unwrap_or_default()
+                        .include_getter
+                {
+                    continue;
+                }
                 let field_ty = &f.ty;
                 let getter_name = Ident::new(&format!("get_{}", i), f.span());
                 let index = syn::Index::from(i); // Using syn::Index::from
+                let hook = field_access_hook(&struct_attrs, name, &index);
+                let vis = getter_visibility(&struct_attrs, &input.vis, f);
+                let (unsafety, safety_doc) = getter_safety(&struct_attrs, name, false);
+                let bounds = getter_bounds(&struct_attrs, &FieldAttributes::default(), field_ty);
                 let getter = quote! {
-                    pub fn #getter_name(&self) -> &#field_ty {
+                    #safety_doc #vis #unsafety fn #getter_name(&self) -> &#field_ty #bounds {
+                        #hook
                         &self.#index
                     }
                 };
                 getters.push(getter);
+                if struct_attrs.readonly_proxy {
+                    let call = proxy_call(&getter_name, &quote! {}, &unsafety);
+                    readonly_getters.push(quote! {
+                        #safety_doc #vis #unsafety fn #getter_name(&self) -> &#field_ty #bounds {
+                            #call
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    // Generate `Option`-returning getters for the named fields of enum variants.
+    if let Data::Enum(data_enum) = &input.data {
+        getters.extend(generate_enum_getters(&input, data_enum, &struct_attrs));
+    }
+
+    // Generate a constant holding the number of fields if requested.
+    if struct_attrs.field_count {
+        match &input.data {
+            Data::Struct(data_struct) => {
+                let count = data_struct.fields.len();
+                consts.push(quote! {
+                    pub const FIELD_COUNT: usize = #count;
+                });
+            }
+            Data::Enum(_) | Data::Union(_) => {
+                consts.push(
+                    syn::Error::new_spanned(name, "`field_count` is not supported on enums")
+                        .to_compile_error(),
+                );
             }
         }
     }
 
     // Generate a `new` function if not skipped.
-    let new_fn = if !skip_new {
-        generate_new_fn(&input.data)
+    // `builder` and `bon::Builder` replace `new` unless it is explicitly requested as well.
+    let explicit_new = struct_attrs.new || struct_attrs.new_name.is_some();
+    let replaced_new = struct_attrs.builder || struct_attrs.bon_builder;
+    let new_fn = if !struct_attrs.skip_new && (!replaced_new || explicit_new) {
+        let new_name = struct_attrs
+            .new_name
+            .clone()
+            .unwrap_or_else(|| Ident::new(NEW, proc_macro2::Span::call_site()));
+        let vis = struct_attrs
+            .visibility
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote! { pub });
+        generate_new_fn(&input, &vis, &new_name, struct_attrs.async_new.as_ref())
     } else {
         quote! {}
     };
 
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // Declare the counter behind `auto_id` fields.
+    if let Data::Struct(data_struct) = &input.data {
+        if data_struct
+            .fields
+            .iter()
+            .any(|f| parse_field_attributes(&f.attrs).unwrap_or_default().auto_id)
+        {
+            let counter = id_counter(name);
+            module_items.push(quote! {
+                static #counter: ::std::sync::atomic::AtomicU64 = ::std::sync::atomic::AtomicU64::new(1);
+            });
+        }
+    }
 
-    // Combine getters, mutable getters, and the `new` function into the impl block..
-    let expanded = quote! {
-        impl #impl_generics #name #ty_generics #where_clause {
-            #new_fn
+    // Generate a free constructor function if requested.
+    if let Some(fn_name) = &struct_attrs.free_new {
+        module_items.push(generate_free_new_fn(
+            &input,
+            generics,
+            fn_name,
+            struct_attrs.async_new.as_ref(),
+        ));
+    }
 
-            #(#getters)*
-            #(#mut_getters)*
-        }
+    // Generate a builder if requested.
+    let builder = if struct_attrs.builder {
+        generate_builder(&input)
+    } else {
+        quote! {}
     };
 
-    // Convert to a TokenStream and return.
-    TokenStream::from(expanded)
-}
+    // Generate a validating `TryFrom` impl if requested.
+    if let Some(source) = &struct_attrs.try_from {
+        module_items.push(generate_try_from(&input, source));
+    }
 
-fn generate_new_fn(data: &Data) -> proc_macro2::TokenStream {
-    match data {
-        Data::Struct(data_struct) => match &data_struct.fields {
-            Fields::Named(fields_named) => {
-                let args = fields_named.named.iter().map(|f| {
-                    let field_name = f.ident.as_ref().unwrap();
-                    let field_ty = &f.ty;
-                    quote! { #field_name: #field_ty }
-                });
-                let assignments = fields_named.named.iter().map(|f| {
-                    let field_name = f.ident.as_ref().unwrap();
-                    quote! { #field_name: #field_name }
-                });
-                quote! {
-                    pub fn new(#(#args),*) -> Self {
-                        Self {
-                            #(#assignments),*
-                        }
-                    }
-                }
-            }
-            Fields::Unnamed(fields_unnamed) => {
-                let args = fields_unnamed.unnamed.iter().enumerate().map(|(i, f)| {
-                    let field_ty = &f.ty;
-                    let ident = Ident::new(&format!("field_{}", i), f.span());
-                    quote! { #ident: #field_ty }
-                });
-                let assignments = fields_unnamed.unnamed.iter().enumerate().map(|(i, _)| {
-                    let ident = Ident::new(&format!("field_{}", i), proc_macro2::Span::call_site());
-                    quote! { #ident }
-                });
+    // Generate an environment-reading constructor if requested.
+    let from_env_fn = match &struct_attrs.from_env {
+        Some(prefix) => generate_from_env_fn(&input.data, &prefix.value()),
+        None => quote! {},
+    };
+
+    // Generate a delegating `Iterator` impl if requested.
+    if let Some((item_ty, field)) = &struct_attrs.impl_iterator {
+        module_items.push(generate_iterator_impl(
+            &input,
+            generics,
+            item_ty,
+            field.as_ref(),
+        ));
+    }
+
+    // Generate a method returning `&Self` if requested.
+    let self_ref_fn = match &struct_attrs.self_ref {
+        Some(method) => quote! {
+            pub fn #method(&self) -> &Self {
+                self
+            }
+        },
+        None => quote! {},
+    };
+
+    // Generate getters splitting a buffer at an index field if requested.
+    let split_getters = match &struct_attrs.split_getter {
+        Some((buffer, at)) => generate_split_getters(&input, buffer, at),
+        None => quote! {},
+    };
+
+    // Generate ratios between numeric fields if requested.
+    let ratio_fns = struct_attrs.ratios.iter().map(|ratio| {
+        let Ratio {
+            name: ratio_name,
+            numerator,
+            denominator,
+            on_zero,
+        } = ratio;
+        let on_zero = if on_zero
+            .as_ref()
+            .is_some_and(|on_zero| on_zero.value() == "nan")
+        {
+            quote! { ::std::primitive::f64::NAN }
+        } else {
+            quote! { 0.0 }
+        };
+        quote! {
+            pub fn #ratio_name(&self) -> f64 {
+                let denominator = self.#denominator as f64;
+                if denominator == 0.0 {
+                    #on_zero
+                } else {
+                    self.#numerator as f64 / denominator
+                }
+            }
+        }
+    });
+
+    // Generate a checksum over byte fields if requested.
+    let checksum_fn = match &struct_attrs.checksum {
+        Some((fields, algo)) => generate_checksum_fn(fields, algo),
+        None => quote! {},
+    };
+
+    // Generate a row view over parallel `Vec` fields if requested.
+    if struct_attrs.struct_of_arrays_view {
+        module_items.push(generate_row_view(&input, generics));
+    }
+
+    // Generate `Hash`, `PartialEq` and `Ord` impls over a subset of the fields if requested.
+    if let Some(fields) = &struct_attrs.impl_hash_by {
+        module_items.push(generate_impl_by(&input, generics, "Hash", fields));
+    }
+    if let Some(fields) = &struct_attrs.impl_partial_eq_by {
+        module_items.push(generate_impl_by(&input, generics, "PartialEq", fields));
+    }
+    if let Some(fields) = &struct_attrs.ord_by {
+        module_items.push(generate_impl_by(&input, generics, "Ord", fields));
+    }
+
+    // Generate a read-only proxy exposing only the getters if requested.
+    if struct_attrs.readonly_proxy {
+        module_items.push(generate_readonly_proxy(&input, generics, &readonly_getters));
+    }
+
+    // Generate arithmetic operators for a newtype if requested.
+    if struct_attrs.newtype_ops {
+        module_items.push(generate_newtype_ops(&input, generics));
+    }
+
+    // Generate an enum listing the fields if requested.
+    if struct_attrs.field_enum {
+        module_items.push(generate_field_enum(&input));
+    }
+
+    let allow_warnings = if struct_attrs.suppress_warnings {
+        quote! { #[allow(clippy::all, unused)] }
+    } else {
+        quote! {}
+    };
+
+    // Combine getters, mutable getters, and the `new` function into the impl block..
+    let expanded = quote! {
+        #allow_warnings
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#consts)*
+
+            #new_fn
+            #from_env_fn
+            #self_ref_fn
+            #checksum_fn
+            #(#ratio_fns)*
+            #split_getters
+
+            #(#getters)*
+            #(#mut_getters)*
+        }
+
+        #(#module_items)*
+
+        #builder
+    };
+
+    // Convert to a TokenStream and return.
+    TokenStream::from(expanded)
+}
+
+/// Generates a getter per field name found in the struct-like variants of an enum.
+///
+/// Each getter matches every variant carrying the field and returns `None` for the others.
+/// Unit and tuple variants carry no named fields and only ever return `None`. Variants sharing a
+/// field name must agree on its type, otherwise a compile error points at the mismatch.
+fn generate_enum_getters(
+    input: &DeriveInput,
+    data_enum: &syn::DataEnum,
+    struct_attrs: &StructAttributes,
+) -> Vec<proc_macro2::TokenStream> {
+    let mut fields: Vec<(&syn::Field, Vec<&Ident>)> = Vec::new();
+    let mut errors = Vec::new();
+    for variant in &data_enum.variants {
+        if let Fields::Named(fields_named) = &variant.fields {
+            for f in &fields_named.named {
+                let field_ty = &f.ty;
+                match fields.iter_mut().find(|(seen, _)| seen.ident == f.ident) {
+                    Some((seen, variants)) => {
+                        let seen_ty = &seen.ty;
+                        if quote! { #seen_ty }.to_string() == quote! { #field_ty }.to_string() {
+                            variants.push(&variant.ident);
+                        } else {
+                            let message = format!(
+                                "field `{}` has type `{}` in variant `{}` but a different type here",
+                                f.ident.as_ref().unwrap(),
+                                quote! { #seen_ty },
+                                variants[0],
+                            );
+                            errors.push(
+                                syn::Error::new_spanned(field_ty, message).to_compile_error(),
+                            );
+                        }
+                    }
+                    None => fields.push((f, vec![&variant.ident])),
+                }
+            }
+        }
+    }
+
+    fields
+        .into_iter()
+        .filter_map(|(f, variants)| {
+            let attrs = parse_field_attributes(&f.attrs).unwrap_or_default();
+            if attrs.skip_getter || (struct_attrs.skip_all && !attrs.include_getter) {
+                return None;
+            }
+            Some((f, attrs, variants))
+        })
+        .map(|(f, attrs, variants)| {
+            let field_name = f.ident.as_ref().unwrap();
+            let field_ty = &f.ty;
+            // `getter_name` was validated as an identifier when the attributes were parsed.
+            let base_name = attrs
+                .getter_name
+                .as_ref()
+                .and_then(|lit| lit.parse::<Ident>().ok())
+                .unwrap_or_else(|| field_name.clone());
+            let method_name = getter_name(struct_attrs, &base_name);
+            let hook = field_access_hook(struct_attrs, &input.ident, field_name);
+            let vis = getter_visibility(struct_attrs, &input.vis, f);
+            let (unsafety, safety_doc) = getter_safety(struct_attrs, &input.ident, attrs.safe_getter);
+            let bounds = getter_bounds(struct_attrs, &attrs, field_ty);
+            let fallback = if variants.len() < data_enum.variants.len() {
+                quote! { _ => ::std::option::Option::None, }
+            } else {
+                quote! {}
+            };
+            quote! {
+                #safety_doc #vis #unsafety fn #method_name(&self) -> ::std::option::Option<&#field_ty> #bounds {
+                    #hook
+                    match self {
+                        #(Self::#variants { #field_name, .. })|* => ::std::option::Option::Some(#field_name),
+                        #fallback
+                    }
+                }
+            }
+        })
+        .chain(errors)
+        .collect()
+}
+
+/// Generates the `new` constructor for the struct.
+///
+/// The constructor takes every field by value, so it is bounded by `Self: Sized` (and the last
+/// field, the only one allowed to be unsized, by `Sized`). The getters only take `&self` and
+/// stay callable when a generic `?Sized` parameter is instantiated with an unsized type, such as
+/// `Foo<[u8]>` or `Foo<dyn Trait>`. A struct whose last field is unsized as written (`[T]`, `str`
+/// or `dyn Trait`) can never be built by value and gets no constructor.
+///
+/// The constructors take one argument per field, so `clippy::too_many_arguments` is allowed on them.
+///
+/// With `async_init`, the constructors become `async fn`s that pass the built value through
+/// `async_init(Self).await` before returning it.
+fn generate_new_fn(
+    input: &DeriveInput,
+    vis: &syn::Visibility,
+    new_name: &Ident,
+    async_init: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    let Some(fields) = constructor_fields(input) else {
+        return quote! {};
+    };
+
+    let bindings = constructor_bindings(fields);
+    let (args, defaults) = constructor_args(&input.ident, fields, &bindings);
+    let construct = constructor_expr(fields, &bindings, &quote! { Self });
+    let (construct, asyncness) = async_constructor(construct, async_init);
+    let sized = sized_bound(fields);
+
+    let validations = field_validations(fields, &bindings);
+    if validations.is_empty() {
+        return quote! {
+            #[allow(clippy::too_many_arguments)]
+            #vis #asyncness fn #new_name(#(#args),*) -> Self #sized {
+                #(#defaults)*
+                #construct
+            }
+        };
+    }
+
+    let try_new_name = Ident::new(&format!("try_{}", new_name), new_name.span());
+    let asserts = validations.iter().map(|(condition, message)| {
+        quote! { assert!(#condition, #message); }
+    });
+    let checks = validation_checks(&validations);
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #vis #asyncness fn #new_name(#(#args),*) -> Self #sized {
+            #(#defaults)*
+            #(#asserts)*
+            #construct
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        #vis #asyncness fn #try_new_name(#(#args),*) -> ::std::result::Result<Self, &'static str> #sized {
+            #(#defaults)*
+            #(#checks)*
+            ::std::result::Result::Ok(#construct)
+        }
+    }
+}
+
+/// Returns the fields the constructors take, or `None` for enums, unit structs and structs whose
+/// last field is unsized as written, which get no constructor.
+fn constructor_fields(input: &DeriveInput) -> Option<&Fields> {
+    let fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        Data::Enum(_) | Data::Union(_) => return None,
+    };
+    if let Fields::Unit = fields {
+        return None;
+    }
+    if fields
+        .iter()
+        .last()
+        .is_some_and(|last| is_unsized(&last.ty))
+    {
+        return None;
+    }
+    Some(fields)
+}
+
+/// Passes the constructed value through `async_init(..).await` if set, returning the
+/// expression along with the `async` keyword the constructor then needs.
+fn async_constructor(
+    construct: proc_macro2::TokenStream,
+    async_init: Option<&syn::Path>,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match async_init {
+        Some(init) => (quote! { #init(#construct).await }, quote! { async }),
+        None => (construct, quote! {}),
+    }
+}
+
+/// Names the static counter handing out `auto_id` values for the struct.
+fn id_counter(name: &Ident) -> Ident {
+    Ident::new(
+        &format!("__{}_NEXT_ID", name.to_string().to_uppercase()),
+        name.span(),
+    )
+}
+
+/// Names the constructor argument of each field: named fields keep their name, unnamed ones
+/// become `field_<index>`.
+fn constructor_bindings(fields: &Fields) -> Vec<Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            f.ident
+                .clone()
+                .unwrap_or_else(|| Ident::new(&format!("field_{}", i), f.span()))
+        })
+        .collect()
+}
+
+/// Generates a free function constructing the struct from the same arguments as `new`.
+///
+/// Like `new`, it is skipped for structs that cannot be built by value and becomes an `async fn`
+/// with `async_init`.
+fn generate_free_new_fn(
+    input: &DeriveInput,
+    generics: &syn::Generics,
+    fn_name: &Ident,
+    async_init: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    let Some(fields) = constructor_fields(input) else {
+        return quote! {};
+    };
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let bindings = constructor_bindings(fields);
+    let (args, defaults) = constructor_args(name, fields, &bindings);
+    let construct = constructor_expr(fields, &bindings, &quote! { #name });
+    let (construct, asyncness) = async_constructor(construct, async_init);
+    let asserts = field_validations(fields, &bindings)
+        .into_iter()
+        .map(|(condition, message)| {
+            quote! { assert!(#condition, #message); }
+        });
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #vis #asyncness fn #fn_name #impl_generics(#(#args),*) -> #name #ty_generics #where_clause {
+            #(#defaults)*
+            #(#asserts)*
+            #construct
+        }
+    }
+}
+
+/// Splits the fields into constructor arguments and `new_default`, `auto_now` or `auto_id`
+/// initializations.
+fn constructor_args(
+    name: &Ident,
+    fields: &Fields,
+    bindings: &[Ident],
+) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
+    let mut args = Vec::new();
+    let mut defaults = Vec::new();
+    for (f, binding) in fields.iter().zip(bindings) {
+        let field_ty = &f.ty;
+        let attrs = parse_field_attributes(&f.attrs).unwrap_or_default();
+        if let Some(init) = attrs.new_default {
+            defaults.push(quote! { let #binding: #field_ty = (#init)(); });
+        } else if attrs.auto_id {
+            let counter = id_counter(name);
+            defaults.push(quote! {
+                let #binding = #counter.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed) as #field_ty;
+            });
+        } else if attrs.auto_now {
+            if is_type(field_ty, "Instant") || is_type(field_ty, "SystemTime") {
+                defaults.push(quote! { let #binding: #field_ty = <#field_ty>::now(); });
+            } else {
+                // Keep the field as an argument so that only the error below is reported.
+                args.push(quote! { #binding: #field_ty });
+                defaults.push(
+                    syn::Error::new_spanned(
+                        field_ty,
+                        "`auto_now` requires a `SystemTime` or `Instant` field",
+                    )
+                    .to_compile_error(),
+                );
+            }
+        } else if attrs.into_arg {
+            args.push(quote! { #binding: impl ::std::convert::Into<#field_ty> });
+            defaults.push(quote! { let #binding: #field_ty = #binding.into(); });
+        } else {
+            args.push(quote! { #binding: #field_ty });
+        }
+    }
+    (args, defaults)
+}
+
+/// Builds the `Self { .. }` or `Self(..)` expression, or the same with `ctor` in place of `Self`,
+/// from the constructor bindings.
+fn constructor_expr(
+    fields: &Fields,
+    bindings: &[Ident],
+    ctor: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(_) => {
+            let field_names = fields.iter().map(|f| f.ident.as_ref().unwrap());
+            quote! { #ctor { #(#field_names: #bindings),* } }
+        }
+        _ => quote! { #ctor(#(#bindings),*) },
+    }
+}
+
+/// Collects the `validate` and `range` conditions of the fields along with their error message.
+fn field_validations(
+    fields: &Fields,
+    bindings: &[Ident],
+) -> Vec<(proc_macro2::TokenStream, String)> {
+    let mut validations = Vec::new();
+    for (i, (f, binding)) in fields.iter().zip(bindings).enumerate() {
+        let attrs = parse_field_attributes(&f.attrs).unwrap_or_default();
+        let field = f
+            .ident
+            .as_ref()
+            .map_or(i.to_string(), |ident| ident.to_string());
+        if let Some(validator) = attrs.validate {
+            let message = format!(
+                "field `{}` failed validation `{}`",
+                field,
+                quote! { #validator }.to_string().replace(' ', "")
+            );
+            validations.push((quote! { #validator(&#binding) }, message));
+        }
+        if let Some(range) = attrs.range {
+            let message = format!(
+                "field `{}` is out of range `{}`",
+                field,
+                quote! { #range }.to_string().replace(' ', "")
+            );
+            validations.push((
+                quote! { ::std::ops::RangeBounds::contains(&(#range), &#binding) },
+                message,
+            ));
+        }
+    }
+    validations
+}
+
+/// Builds the early returns rejecting invalid constructor arguments.
+fn validation_checks(
+    validations: &[(proc_macro2::TokenStream, String)],
+) -> Vec<proc_macro2::TokenStream> {
+    validations
+        .iter()
+        .map(|(condition, message)| {
+            quote! {
+                if !(#condition) {
+                    return ::std::result::Result::Err(#message);
+                }
+            }
+        })
+        .collect()
+}
+
+/// Generates `impl TryFrom<Source>` moving each field out of the same-named field of `Source`
+/// and running the `validate` functions.
+fn generate_try_from(input: &DeriveInput, source: &syn::Type) -> proc_macro2::TokenStream {
+    let fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        Data::Enum(_) | Data::Union(_) => return quote! {},
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let bindings = constructor_bindings(fields);
+    let moves = fields
+        .iter()
+        .zip(&bindings)
+        .enumerate()
+        .map(|(i, (f, binding))| {
+            let member = match &f.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(syn::Index::from(i)),
+            };
+            quote! { let #binding = source.#member; }
+        });
+    let checks = validation_checks(&field_validations(fields, &bindings));
+    let construct = constructor_expr(fields, &bindings, &quote! { Self });
+
+    quote! {
+        impl #impl_generics ::std::convert::TryFrom<#source> for #name #ty_generics #where_clause {
+            type Error = &'static str;
+
+            fn try_from(source: #source) -> ::std::result::Result<Self, Self::Error> {
+                #(#moves)*
+                #(#checks)*
+                ::std::result::Result::Ok(#construct)
+            }
+        }
+    }
+}
+
+/// Generates `from_env()`, parsing each field from its environment variable.
+fn generate_from_env_fn(data: &Data, prefix: &str) -> proc_macro2::TokenStream {
+    let fields = match data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        Data::Enum(_) | Data::Union(_) => return quote! {},
+    };
+
+    let bindings = constructor_bindings(fields);
+    let reads = fields
+        .iter()
+        .zip(&bindings)
+        .enumerate()
+        .map(|(i, (f, binding))| {
+            let field_ty = &f.ty;
+            let var = parse_field_attributes(&f.attrs)
+                .unwrap_or_default()
+                .env
+                .map_or_else(
+                    || {
+                        let field = f
+                            .ident
+                            .as_ref()
+                            .map_or(i.to_string(), |ident| ident.to_string());
+                        format!(
+                            "{}{}",
+                            prefix,
+                            field.trim_start_matches("r#").to_uppercase()
+                        )
+                    },
+                    |env| env.value(),
+                );
+            let missing = format!("environment variable `{}` is not set or not unicode", var);
+            let invalid = format!("environment variable `{}` could not be parsed", var);
+            quote! {
+                let #binding = ::std::env::var(#var)
+                    .map_err(|_| #missing)?
+                    .parse::<#field_ty>()
+                    .map_err(|_| #invalid)?;
+            }
+        });
+    let construct = constructor_expr(fields, &bindings, &quote! { Self });
+    let checks = validation_checks(&field_validations(fields, &bindings));
+
+    quote! {
+        pub fn from_env() -> ::std::result::Result<Self, &'static str> {
+            #(#reads)*
+            #(#checks)*
+            ::std::result::Result::Ok(#construct)
+        }
+    }
+}
+
+/// Generates `impl Iterator` forwarding `next()` to the wrapped iterator field.
+fn generate_iterator_impl(
+    input: &DeriveInput,
+    generics: &syn::Generics,
+    item_ty: &syn::Type,
+    field: Option<&Ident>,
+) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        Data::Enum(_) | Data::Union(_) => return quote! {},
+    };
+    let member =
+        match (field, fields.len()) {
+            (Some(field), _) => syn::Member::Named(field.clone()),
+            (None, 1) => match fields.iter().next().and_then(|f| f.ident.clone()) {
+                Some(ident) => syn::Member::Named(ident),
+                None => syn::Member::Unnamed(syn::Index::from(0)),
+            },
+            (None, _) => return syn::Error::new_spanned(
+                name,
+                "`impl_iterator` needs `field = \"..\"` unless the struct has exactly one field",
+            )
+            .to_compile_error(),
+        };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::std::iter::Iterator for #name #ty_generics #where_clause {
+            type Item = #item_ty;
+
+            fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                ::std::iter::Iterator::next(&mut self.#member)
+            }
+        }
+    }
+}
+
+/// Generates a `<Struct>Row` struct borrowing one element of every `Vec` field, along with
+/// `row(index)` reading the elements at the same index.
+fn generate_row_view(input: &DeriveInput, generics: &syn::Generics) -> proc_macro2::TokenStream {
+    let fields_named = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => fields_named,
+            _ => return quote! {},
+        },
+        _ => return quote! {},
+    };
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let row_name = Ident::new(&format!("{}Row", name), name.span());
+    let mut field_names = Vec::new();
+    let mut elem_tys = Vec::new();
+    for f in fields_named.named.iter() {
+        match wrapped_type(&f.ty, "Vec") {
+            Some(elem_ty) => {
+                field_names.push(f.ident.as_ref().unwrap());
+                elem_tys.push(elem_ty);
+            }
+            None => {
+                return syn::Error::new_spanned(
+                    &f.ty,
+                    "`struct_of_arrays_view` requires every field to be a `Vec<T>`",
+                )
+                .to_compile_error()
+            }
+        }
+    }
+
+    let mut row_generics = generics.clone();
+    row_generics.params.insert(0, syn::parse_quote! { 'row });
+    let (row_impl_generics, row_ty_generics, _) = row_generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #vis struct #row_name #row_impl_generics #where_clause {
+            #(pub #field_names: &'row #elem_tys),*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn row<'row>(&'row self, index: usize) -> ::std::option::Option<#row_name #row_ty_generics> {
+                ::std::option::Option::Some(#row_name {
+                    #(#field_names: self.#field_names.get(index)?),*
+                })
+            }
+        }
+    }
+}
+
+/// Generates `impl Hash` (or `impl PartialEq` and `Eq` when `trait_name` is `PartialEq`, `impl Ord` and
+/// `PartialOrd` when it is `Ord`) considering only the given fields.
+fn generate_impl_by(
+    input: &DeriveInput,
+    generics: &syn::Generics,
+    trait_name: &str,
+    fields: &[Ident],
+) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let declared: Vec<_> = match &input.data {
+        Data::Struct(data_struct) => data_struct
+            .fields
+            .iter()
+            .filter_map(|f| f.ident.as_ref())
+            .collect(),
+        Data::Enum(_) | Data::Union(_) => return quote! {},
+    };
+    if let Some(unknown) = fields.iter().find(|field| !declared.contains(field)) {
+        return syn::Error::new_spanned(unknown, format!("`{}` has no field `{}`", name, unknown))
+            .to_compile_error();
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    if trait_name == "Ord" {
+        quote! {
+            impl #impl_generics ::std::cmp::Ord for #name #ty_generics #where_clause {
+                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    ::std::cmp::Ord::cmp(&(#(&self.#fields,)*), &(#(&other.#fields,)*))
+                }
+            }
+
+            impl #impl_generics ::std::cmp::PartialOrd for #name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                    ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+                }
+            }
+        }
+    } else if trait_name == "PartialEq" {
+        quote! {
+            impl #impl_generics ::std::cmp::PartialEq for #name #ty_generics #where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    true #(&& self.#fields == other.#fields)*
+                }
+            }
+
+            impl #impl_generics ::std::cmp::Eq for #name #ty_generics #where_clause {}
+        }
+    } else {
+        quote! {
+            impl #impl_generics ::std::hash::Hash for #name #ty_generics #where_clause {
+                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                    #(::std::hash::Hash::hash(&self.#fields, state);)*
+                }
+            }
+        }
+    }
+}
+
+/// Generates a `<Struct>ReadOnly<'ro>` wrapper around `&'ro Struct` carrying the given delegating
+/// getters, along with `as_readonly()` creating it.
+fn generate_readonly_proxy(
+    input: &DeriveInput,
+    generics: &syn::Generics,
+    readonly_getters: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let proxy_name = Ident::new(&format!("{}ReadOnly", name), name.span());
+
+    let mut proxy_generics = generics.clone();
+    proxy_generics.params.insert(0, syn::parse_quote! { 'ro });
+    let (proxy_impl_generics, proxy_ty_generics, _) = proxy_generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut elided_generics = generics.clone();
+    elided_generics.params.insert(0, syn::parse_quote! { '_ });
+    let (_, elided_ty_generics, _) = elided_generics.split_for_impl();
+
+    quote! {
+        #vis struct #proxy_name #proxy_impl_generics (&'ro #name #ty_generics) #where_clause;
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn as_readonly(&self) -> #proxy_name #elided_ty_generics {
+                #proxy_name(self)
+            }
+        }
+
+        impl #proxy_impl_generics #proxy_name #proxy_ty_generics #where_clause {
+            #(#readonly_getters)*
+        }
+    }
+}
+
+/// Builds the body of a read-only proxy method, calling the getter of the same name on the borrowed
+/// struct. Deprecated getters are forwarded with their `#[deprecated]` attribute, so the call itself
+/// does not warn.
+fn proxy_call(
+    getter_name: &Ident,
+    call_args: &proc_macro2::TokenStream,
+    unsafety: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let call = quote! { self.0.#getter_name(#call_args) };
+    if unsafety.is_empty() {
+        quote! {
+            #[allow(deprecated)]
+            let value = #call;
+            value
+        }
+    } else {
+        quote! {
+            #[allow(deprecated)]
+            let value = unsafe { #call };
+            value
+        }
+    }
+}
+
+/// Generates `Add`, `Sub`, `Mul` and `Div` impls for a single-field newtype, applying the operator to
+/// the inner values and wrapping the result.
+fn generate_newtype_ops(input: &DeriveInput, generics: &syn::Generics) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data_struct) if data_struct.fields.len() == 1 => &data_struct.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                name,
+                "`newtype_ops` requires a struct with exactly one field",
+            )
+            .to_compile_error()
+        }
+    };
+    let field = fields.iter().next().unwrap();
+    let inner_ty = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(0)),
+    };
+    let wrap = |value: proc_macro2::TokenStream| match &field.ident {
+        Some(ident) => quote! { Self { #ident: #value } },
+        None => quote! { Self(#value) },
+    };
+
+    let impls = [
+        ("Add", "add"),
+        ("Sub", "sub"),
+        ("Mul", "mul"),
+        ("Div", "div"),
+    ]
+    .into_iter()
+    .map(|(trait_name, method)| {
+        let trait_name = Ident::new(trait_name, name.span());
+        let method = Ident::new(method, name.span());
+        let mut generics = generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! {
+                #inner_ty: ::std::ops::#trait_name<Output = #inner_ty>
+            });
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let result = wrap(quote! { ::std::ops::#trait_name::#method(self.#member, rhs.#member) });
+        quote! {
+            impl #impl_generics ::std::ops::#trait_name for #name #ty_generics #where_clause {
+                type Output = Self;
+
+                fn #method(self, rhs: Self) -> Self {
+                    #result
+                }
+            }
+        }
+    });
+    quote! { #(#impls)* }
+}
+
+/// Generates `head()` and `tail()` splitting the `buffer` field at the index held by the `at` field,
+/// clamped to the buffer length.
+fn generate_split_getters(
+    input: &DeriveInput,
+    buffer: &Ident,
+    at: &Ident,
+) -> proc_macro2::TokenStream {
+    let fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        Data::Enum(_) | Data::Union(_) => return quote! {},
+    };
+    let field_ty = |name: &Ident| {
+        fields
+            .iter()
+            .find(|f| f.ident.as_ref() == Some(name))
+            .map(|f| &f.ty)
+    };
+    let Some(buffer_ty) = field_ty(buffer) else {
+        return syn::Error::new_spanned(
+            buffer,
+            format!("`{}` has no field `{}`", input.ident, buffer),
+        )
+        .to_compile_error();
+    };
+    if field_ty(at).is_none() {
+        return syn::Error::new_spanned(at, format!("`{}` has no field `{}`", input.ident, at))
+            .to_compile_error();
+    }
+    let Some(elem_ty) = element_type(buffer_ty) else {
+        return syn::Error::new_spanned(
+            buffer_ty,
+            "`split_getter` requires a `Vec<T>`, array or slice buffer",
+        )
+        .to_compile_error();
+    };
+    let vis = &input.vis;
+
+    quote! {
+        #vis fn head(&self) -> &[#elem_ty] {
+            let at = ::std::cmp::min(self.#at, self.#buffer.len());
+            &self.#buffer[..at]
+        }
+
+        #vis fn tail(&self) -> &[#elem_ty] {
+            let at = ::std::cmp::min(self.#at, self.#buffer.len());
+            &self.#buffer[at..]
+        }
+    }
+}
+
+/// Generates `checksum()` hashing the given byte fields in order.
+fn generate_checksum_fn(fields: &[Ident], algo: &LitStr) -> proc_macro2::TokenStream {
+    if algo.value() != "crc32" {
+        return syn::Error::new_spanned(
+            algo,
+            "unsupported `checksum` algorithm, expected \"crc32\"",
+        )
+        .to_compile_error();
+    }
+    if !cfg!(feature = "crc32") {
+        return syn::Error::new_spanned(algo, "`checksum` requires the `crc32` feature")
+            .to_compile_error();
+    }
+    quote! {
+        pub fn checksum(&self) -> u32 {
+            let mut hasher = ::crc32fast::Hasher::new();
+            #(hasher.update(::core::convert::AsRef::<[u8]>::as_ref(&self.#fields));)*
+            hasher.finalize()
+        }
+    }
+}
+
+/// Generates a `<Struct>Field` enum whose variants are the struct's named fields in `PascalCase`.
+fn generate_field_enum(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let fields_named = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => fields_named,
+            _ => return quote! {},
+        },
+        _ => return quote! {},
+    };
+
+    let vis = &input.vis;
+    let enum_name = Ident::new(&format!("{}Field", input.ident), input.ident.span());
+    let (variants, names): (Vec<_>, Vec<_>) = fields_named
+        .named
+        .iter()
+        .map(|f| {
+            let field_name = f.ident.as_ref().unwrap().to_string();
+            let field_name = field_name.trim_start_matches("r#").to_string();
+            let variant: String = field_name
+                .split('_')
+                .filter(|part| !part.is_empty())
+                .map(|part| {
+                    let mut chars = part.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                        .unwrap_or_default()
+                })
+                .collect();
+            (Ident::new(&variant, f.span()), field_name)
+        })
+        .unzip();
+
+    quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #enum_name {
+            #(#variants),*
+        }
+
+        impl #enum_name {
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #names),*
+                }
+            }
+        }
+    }
+}
+
+/// Builds the `where` clause that keeps by-value constructors usable on possibly unsized structs.
+fn sized_bound(fields: &Fields) -> proc_macro2::TokenStream {
+    match fields.iter().last() {
+        Some(last) => {
+            let last_ty = &last.ty;
+            quote! { where Self: Sized, #last_ty: Sized }
+        }
+        None => quote! { where Self: Sized },
+    }
+}
+
+/// Checks whether a type is unsized as written: a slice, `str` or a trait object.
+fn is_unsized(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Slice(_) | syn::Type::TraitObject(_) => true,
+        syn::Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident("str"),
+        syn::Type::Paren(paren) => is_unsized(&paren.elem),
+        syn::Type::Group(group) => is_unsized(&group.elem),
+        _ => false,
+    }
+}
+
+/// Generates a `<Struct>Builder` type along with the `builder()` method that creates it.
+///
+/// Only structs with named fields get a builder. Every field must be set before `build()`,
+/// which otherwise returns an error naming the missing field.
+fn generate_builder(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let fields_named = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => fields_named,
+            _ => return quote! {},
+        },
+        _ => return quote! {},
+    };
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let builder_name = Ident::new(&format!("{}Builder", name), name.span());
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields = fields_named.named.iter().map(|f| {
+        let field_name = f.ident.as_ref().unwrap();
+        let field_ty = &f.ty;
+        quote! { #field_name: ::std::option::Option<#field_ty> }
+    });
+    let empty = fields_named.named.iter().map(|f| {
+        let field_name = f.ident.as_ref().unwrap();
+        quote! { #field_name: ::std::option::Option::None }
+    });
+    let setters = fields_named.named.iter().map(|f| {
+        let field_name = f.ident.as_ref().unwrap();
+        let field_ty = &f.ty;
+        let setter_name = affixed_name("with_", field_name, "");
+        match parse_field_attributes(&f.attrs).unwrap_or_default().builder_validate {
+            Some(validator) => {
+                let message = format!(
+                    "field `{}` failed validation `{}`",
+                    field_name,
+                    quote! { #validator }.to_string().replace(' ', "")
+                );
                 quote! {
-                    pub fn new(#(#args),*) -> Self {
-                        Self(#(#assignments),*)
+                    pub fn #setter_name(mut self, value: #field_ty) -> ::std::result::Result<Self, &'static str> {
+                        if !#validator(&value) {
+                            return ::std::result::Result::Err(#message);
+                        }
+                        self.#field_name = ::std::option::Option::Some(value);
+                        ::std::result::Result::Ok(self)
+                    }
+                }
+            }
+            None => quote! {
+                pub fn #setter_name(mut self, value: #field_ty) -> Self {
+                    self.#field_name = ::std::option::Option::Some(value);
+                    self
+                }
+            },
+        }
+    });
+    let assignments = fields_named.named.iter().map(|f| {
+        let field_name = f.ident.as_ref().unwrap();
+        quote! {
+            #field_name: self.#field_name.ok_or(concat!("field `", stringify!(#field_name), "` is not set"))?
+        }
+    });
+
+    quote! {
+        #vis struct #builder_name #generics #where_clause {
+            #(#fields),*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn builder() -> #builder_name #ty_generics {
+                #builder_name {
+                    #(#empty),*
+                }
+            }
+        }
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #(#setters)*
+
+            pub fn build(self) -> ::std::result::Result<#name #ty_generics, &'static str> {
+                ::std::result::Result::Ok(#name {
+                    #(#assignments),*
+                })
+            }
+        }
+    }
+}
+
+/// Returns the generic arguments of `ty` if it is a path type whose last segment is `wrapper`,
+/// e.g. `[K, V]` for `wrapper = "HashMap"` and `ty = std::collections::HashMap<K, V>`.
+fn wrapped_types<'a>(ty: &'a syn::Type, wrapper: &str) -> Option<Vec<&'a syn::Type>> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => Some(
+            args.args
+                .iter()
+                .filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `ty` is a path type whose last segment is `name`, e.g. `std::time::Duration`.
+fn is_type(ty: &syn::Type, name: &str) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name),
+        _ => false,
+    }
+}
+
+/// Returns the single generic argument of `ty` if it is `wrapper<T>`, e.g. `T` for `Option<T>`.
+fn wrapped_type<'a>(ty: &'a syn::Type, wrapper: &str) -> Option<&'a syn::Type> {
+    match wrapped_types(ty, wrapper)?.as_slice() {
+        [inner] => Some(inner),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `ident` appears anywhere in the tokens of `ty`, e.g. a generic parameter.
+fn mentions_ident(ty: &syn::Type, ident: &Ident) -> bool {
+    fn walk(tokens: proc_macro2::TokenStream, ident: &Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ref other) => other == ident,
+            proc_macro2::TokenTree::Group(group) => walk(group.stream(), ident),
+            _ => false,
+        })
+    }
+    walk(quote! { #ty }, ident)
+}
+
+/// Formats a `SystemTime` expression as an RFC 3339 UTC timestamp with second precision.
+fn system_time_rfc3339(time: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {{
+        let secs: i64 = match #time.duration_since(::std::time::UNIX_EPOCH) {
+            ::std::result::Result::Ok(since) => since.as_secs() as i64,
+            ::std::result::Result::Err(err) => {
+                let before = err.duration();
+                -(before.as_secs() as i64) - i64::from(before.subsec_nanos() > 0)
+            }
+        };
+        let (days, secs_of_day) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+        // Civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        ::std::format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            secs_of_day / 3_600,
+            secs_of_day % 3_600 / 60,
+            secs_of_day % 60
+        )
+    }}
+}
+
+/// Returns the conventional `AsRef` target of an owning type, e.g. `str` for `String` and `Box<str>`,
+/// `Path` for `PathBuf` or `[T]` for `Vec<T>`.
+fn as_ref_target(ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+    if let Some(elem_ty) = wrapped_type(ty, "Vec") {
+        return Some(quote! { [#elem_ty] });
+    }
+    for pointer in ["Box", "Rc", "Arc"] {
+        if let Some(inner_ty) = wrapped_type(ty, pointer) {
+            return Some(quote! { #inner_ty });
+        }
+    }
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    match type_path.path.segments.last()?.ident.to_string().as_str() {
+        "String" => Some(quote! { str }),
+        "PathBuf" => Some(quote! { ::std::path::Path }),
+        "OsString" => Some(quote! { ::std::ffi::OsStr }),
+        "CString" => Some(quote! { ::std::ffi::CStr }),
+        _ => None,
+    }
+}
+
+/// Converts a `CamelCase` identifier into `snake_case`, e.g. `HttpStatus` into `http_status`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Returns the element type of a `Vec<T>`, `[T; N]`, `[T]`, `Box<[T]>` or `&[T]`.
+fn element_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if let Some(elem_ty) = wrapped_type(ty, "Vec") {
+        return Some(elem_ty);
+    }
+    match ty {
+        syn::Type::Array(array) => Some(&array.elem),
+        syn::Type::Slice(slice) => Some(&slice.elem),
+        syn::Type::Reference(reference) => match &*reference.elem {
+            syn::Type::Slice(slice) => Some(&slice.elem),
+            _ => None,
+        },
+        _ => match wrapped_type(ty, "Box") {
+            Some(syn::Type::Slice(slice)) => Some(&slice.elem),
+            _ => None,
+        },
+    }
+}
+
+/// Returns the primitive loaded from a `std::sync::atomic` type, e.g. `u32` for `AtomicU32`.
+fn atomic_primitive(ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+    if let Some(pointee_ty) = wrapped_type(ty, "AtomicPtr") {
+        return Some(quote! { *mut #pointee_ty });
+    }
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let primitive = match type_path.path.segments.last()?.ident.to_string().as_str() {
+        "AtomicBool" => "bool",
+        "AtomicI8" => "i8",
+        "AtomicI16" => "i16",
+        "AtomicI32" => "i32",
+        "AtomicI64" => "i64",
+        "AtomicIsize" => "isize",
+        "AtomicU8" => "u8",
+        "AtomicU16" => "u16",
+        "AtomicU32" => "u32",
+        "AtomicU64" => "u64",
+        "AtomicUsize" => "usize",
+        _ => return None,
+    };
+    let primitive = Ident::new(primitive, ty.span());
+    Some(quote! { #primitive })
+}
+
+/// Returns `T` if `ty` is a mutable reference `&'a mut T`.
+fn mut_reference_type(ty: &syn::Type) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Reference(reference) if reference.mutability.is_some() => Some(&reference.elem),
+        _ => None,
+    }
+}
+
+/// Semantic rules for the visibility of generated getters.
+#[derive(Default)]
+enum VisibilityRule {
+    #[default]
+    AlwaysPub,
+    InheritField,
+    InheritStruct,
+}
+
+/// A `ratio` computed from two numeric fields.
+struct Ratio {
+    name: Ident,
+    numerator: Ident,
+    denominator: Ident,
+    on_zero: Option<LitStr>,
+}
+
+/// Represents parsed struct-level attributes.
+#[derive(Default)]
+struct StructAttributes {
+    skip_new: bool,
+    new: bool,
+    builder: bool,
+    min_fields: Option<usize>,
+    const_field_names: bool,
+    new_name: Option<Ident>,
+    bon_builder: bool,
+    suppress_warnings: bool,
+    visibility_rule: VisibilityRule,
+    unsafe_getters: bool,
+    send_bound: bool,
+    sync_bound: bool,
+    field_enum: bool,
+    try_from: Option<syn::Type>,
+    from_env: Option<LitStr>,
+    where_clause: Option<Vec<syn::WherePredicate>>,
+    impl_iterator: Option<(syn::Type, Option<Ident>)>,
+    field_access_hook: Option<syn::Path>,
+    self_ref: Option<Ident>,
+    checksum: Option<(Vec<Ident>, LitStr)>,
+    free_new: Option<Ident>,
+    struct_of_arrays_view: bool,
+    async_new: Option<syn::Path>,
+    impl_hash_by: Option<Vec<Ident>>,
+    impl_partial_eq_by: Option<Vec<Ident>>,
+    readonly_proxy: bool,
+    newtype_ops: bool,
+    field_count: bool,
+    split_getter: Option<(Ident, Ident)>,
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+    visibility: Option<syn::Visibility>,
+    ord_by: Option<Vec<Ident>>,
+    ratios: Vec<Ratio>,
+    skip_all: bool,
+    set_all: bool,
+}
+
+/// Parses attributes applied to the struct itself and returns a `StructAttributes` instance.
+///
+/// Like `parse_field_attributes`, attributes given a value of the wrong kind fail with an error
+/// pointing at the offending value.
+fn parse_struct_attributes(attrs: &[Attribute]) -> syn::Result<StructAttributes> {
+    attrs
+        .iter()
+        .try_fold(StructAttributes::default(), |mut acc, attr| {
+            match attr.meta {
+                syn::Meta::Path(ref path) if path.is_ident(SKIP_NEW) => acc.skip_new = true,
+                syn::Meta::Path(ref path) if path.is_ident(NEW) => acc.new = true,
+                syn::Meta::Path(ref path) if path.is_ident(BUILDER) => acc.builder = true,
+                syn::Meta::Path(ref path) if path.is_ident(FIELD_ENUM) => acc.field_enum = true,
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_SEND_BOUND) => {
+                    acc.send_bound = true
+                }
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_SYNC_BOUND) => {
+                    acc.sync_bound = true
+                }
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_SUPPRESS_WARNINGS) => {
+                    acc.suppress_warnings = true
+                }
+                syn::Meta::Path(ref path) if path.is_ident(CONST_FIELD_NAMES) => {
+                    acc.const_field_names = true
+                }
+                syn::Meta::Path(ref path) if path.is_ident(CONST_ASSERT_NON_EMPTY) => {
+                    acc.min_fields = Some(acc.min_fields.unwrap_or(0).max(1))
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(NEW_NAME) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.new_name = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::List(ref list) if list.path.is_ident("derive") => {
+                    let derives = list.parse_args_with(
+                        syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                    );
+                    // A bare `Builder` may come from any crate (e.g. `derive_builder`), so only the
+                    // path-qualified `bon::Builder` is recognized.
+                    acc.bon_builder |= derives.is_ok_and(|derives| {
+                        derives.iter().any(|path| {
+                            let segments: Vec<_> = path.segments.iter().collect();
+                            matches!(
+                                segments.as_slice(),
+                                [krate, builder] if krate.ident == "bon" && builder.ident == "Builder"
+                            )
+                        })
+                    });
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_VIS) || nv.path.is_ident(VIS) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.visibility = Some(parse_visibility(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_VISIBILITY_RULE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => {
+                                acc.visibility_rule = match lit.value().as_str() {
+                                    "inherit_field" => VisibilityRule::InheritField,
+                                    "inherit_struct" => VisibilityRule::InheritStruct,
+                                    "always_pub" => VisibilityRule::AlwaysPub,
+                                    _ => {
+                                        return Err(syn::Error::new_spanned(
+                                            lit,
+                                            "unknown `getter_visibility_rule`, expected \"inherit_field\", \"inherit_struct\" or \"always_pub\"",
+                                        ))
+                                    }
+                                }
+                            }
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_SAFETY) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => {
+                                acc.unsafe_getters = match lit.value().as_str() {
+                                    "unsafe" => true,
+                                    "safe" => false,
+                                    _ => {
+                                        return Err(syn::Error::new_spanned(
+                                            lit,
+                                            "unknown `getter_safety`, expected \"unsafe\" or \"safe\"",
+                                        ))
+                                    }
+                                }
+                            }
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(DERIVE_TRY_FROM) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.try_from = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
                     }
                 }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(FROM_ENV) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.from_env = Some(lit.clone()),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_WHERE_CLAUSE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => {
+                                let predicates = lit
+                                    .parse_with(
+                                        syn::punctuated::Punctuated::<
+                                            syn::WherePredicate,
+                                            syn::Token![,],
+                                        >::parse_terminated,
+                                    )
+                                    .map_err(|error| {
+                                        syn::Error::new_spanned(
+                                            lit,
+                                            format!("invalid `getter_where_clause`: {}", error),
+                                        )
+                                    })?;
+                                acc.where_clause = Some(predicates.into_iter().collect());
+                            }
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::Path(ref path) if path.is_ident(IMPL_ITERATOR) => {
+                    return Err(missing_key(path, "item"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(IMPL_ITERATOR) => {
+                    let (mut item_ty, mut field) = (None, None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("item") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            item_ty = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("field") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            field = Some(parse_lit(&lit)?);
+                        } else {
+                            return Err(meta.error(
+                                "unsupported `impl_iterator` option, expected `item` or `field`",
+                            ));
+                        }
+                        Ok(())
+                    })?;
+                    let item_ty = item_ty.ok_or_else(|| missing_key(&list.path, "item"))?;
+                    acc.impl_iterator = Some((item_ty, field));
+                }
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_SELF_REF) => {
+                    acc.self_ref = Some(Ident::new("self_ref", path.span()))
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(GETTER_SELF_REF) => {
+                    acc.self_ref = Some(Ident::new("self_ref", list.path.span()));
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("getter_name") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            acc.self_ref = Some(parse_lit(&lit)?);
+                        }
+                        Ok(())
+                    })?;
+                }
+                syn::Meta::Path(ref path) if path.is_ident(RATIO) => {
+                    return Err(missing_key(path, "name"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(RATIO) => {
+                    let (mut ratio_name, mut numerator, mut denominator, mut on_zero) =
+                        (None, None, None, None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("name") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            ratio_name = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("numerator") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            numerator = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("denominator") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            denominator = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("on_zero") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            if lit.value() != "nan" && lit.value() != "zero" {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "`on_zero` expects \"zero\" or \"nan\"",
+                                ));
+                            }
+                            on_zero = Some(lit);
+                        } else {
+                            return Err(meta.error(
+                                "unsupported `ratio` option, expected `name`, `numerator`, `denominator` or `on_zero`",
+                            ));
+                        }
+                        Ok(())
+                    })?;
+                    acc.ratios.push(Ratio {
+                        name: ratio_name.ok_or_else(|| missing_key(&list.path, "name"))?,
+                        numerator: numerator.ok_or_else(|| missing_key(&list.path, "numerator"))?,
+                        denominator: denominator
+                            .ok_or_else(|| missing_key(&list.path, "denominator"))?,
+                        on_zero,
+                    });
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(ORD_BY) => {
+                    acc.ord_by = list
+                        .parse_args_with(
+                            syn::punctuated::Punctuated::<Ident, syn::Token![,]>::parse_terminated,
+                        )
+                        .map(|fields| Some(fields.into_iter().collect()))?;
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(GETTERS) => {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("prefix") {
+                            acc.prefix = Some(meta.value()?.parse()?);
+                        } else if meta.path.is_ident("suffix") {
+                            acc.suffix = Some(meta.value()?.parse()?);
+                        } else if meta.path.is_ident("set_all") {
+                            acc.set_all = true;
+                        } else if meta.path.is_ident("vis") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            acc.visibility = Some(parse_visibility(&lit)?);
+                        } else {
+                            return Err(meta.error(
+                                "unsupported `getters` option, expected `prefix`, `suffix`, `set_all` or `vis`",
+                            ));
+                        }
+                        Ok(())
+                    })?;
+                }
+                syn::Meta::Path(ref path) if path.is_ident(SPLIT_GETTER) => {
+                    return Err(missing_key(path, "buffer"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(SPLIT_GETTER) => {
+                    let (mut buffer, mut at) = (None, None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("buffer") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            buffer = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("at") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            at = Some(parse_lit(&lit)?);
+                        } else {
+                            return Err(meta.error(
+                                "unsupported `split_getter` option, expected `buffer` or `at`",
+                            ));
+                        }
+                        Ok(())
+                    })?;
+                    acc.split_getter = Some((
+                        buffer.ok_or_else(|| missing_key(&list.path, "buffer"))?,
+                        at.ok_or_else(|| missing_key(&list.path, "at"))?,
+                    ));
+                }
+                syn::Meta::Path(ref path) if path.is_ident(CHECKSUM) => {
+                    return Err(missing_key(path, "over"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(CHECKSUM) => {
+                    let (mut fields, mut algo) = (None, None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("over") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            fields = lit
+                                .parse_with(
+                                    syn::punctuated::Punctuated::<Ident, syn::Token![,]>::parse_terminated,
+                                )
+                                .map(|fields| Some(fields.into_iter().collect()))?;
+                        } else if meta.path.is_ident("algo") {
+                            algo = Some(meta.value()?.parse()?);
+                        } else {
+                            return Err(meta.error(
+                                "unsupported `checksum` option, expected `over` or `algo`",
+                            ));
+                        }
+                        Ok(())
+                    })?;
+                    let fields = fields.ok_or_else(|| missing_key(&list.path, "over"))?;
+                    acc.checksum = Some((
+                        fields,
+                        algo.unwrap_or_else(|| LitStr::new("crc32", list.path.span())),
+                    ));
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(MIN_FIELDS) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Int(lit) => acc.min_fields = Some(lit.base10_parse()?),
+                            lit => {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "`min_fields` expects an integer literal, e.g. `#[min_fields = 2]`",
+                                ))
+                            }
+                        }
+                    }
+                }
+                syn::Meta::Path(ref path) if path.is_ident(READONLY_PROXY) => acc.readonly_proxy = true,
+                syn::Meta::Path(ref path) if path.is_ident(NEWTYPE_OPS) => acc.newtype_ops = true,
+                syn::Meta::Path(ref path) if path.is_ident(SKIP_ALL) => acc.skip_all = true,
+                syn::Meta::Path(ref path) if path.is_ident(FIELD_COUNT) => acc.field_count = true,
+                syn::Meta::Path(ref path) if path.is_ident(STRUCT_OF_ARRAYS_VIEW) => {
+                    acc.struct_of_arrays_view = true
+                }
+                syn::Meta::NameValue(ref nv)
+                    if nv.path.is_ident(IMPL_HASH_BY) || nv.path.is_ident(IMPL_PARTIAL_EQ_BY) =>
+                {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => {
+                                let fields = lit
+                                    .parse_with(
+                                        syn::punctuated::Punctuated::<Ident, syn::Token![,]>::parse_terminated,
+                                    )
+                                    .map(|fields| Some(fields.into_iter().collect()))?;
+                                if nv.path.is_ident(IMPL_HASH_BY) {
+                                    acc.impl_hash_by = fields;
+                                } else {
+                                    acc.impl_partial_eq_by = fields;
+                                }
+                            }
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(ASYNC_NEW) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.async_new = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(FREE_NEW) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.free_new = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(FIELD_ACCESS_HOOK) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.field_access_hook = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                _ => (),
             }
-            Fields::Unit => quote! {},
-        },
-        Data::Enum(_) => quote! {},
-        Data::Union(_) => quote! {},
+            Ok(acc)
+        })
+}
+
+/// Resolves the visibility of a field's getters: the field's `getter_vis`, else the struct's `getter_vis`,
+/// else according to `getter_visibility_rule`.
+fn getter_visibility(
+    struct_attrs: &StructAttributes,
+    struct_vis: &syn::Visibility,
+    field: &syn::Field,
+) -> proc_macro2::TokenStream {
+    let field_vis = &field.vis;
+    if let Some(vis) = parse_field_attributes(&field.attrs)
+        .unwrap_or_default()
+        .visibility
+        .or_else(|| struct_attrs.visibility.clone())
+    {
+        return quote! { #vis };
+    }
+    match struct_attrs.visibility_rule {
+        VisibilityRule::AlwaysPub => quote! { pub },
+        VisibilityRule::InheritField => quote! { #field_vis },
+        VisibilityRule::InheritStruct => quote! { #struct_vis },
+    }
+}
+
+/// Builds the `unsafe` qualifier and the matching `# Safety` doc section for a field's getters.
+fn getter_safety(
+    struct_attrs: &StructAttributes,
+    struct_name: &Ident,
+    safe_getter: bool,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if struct_attrs.unsafe_getters && !safe_getter {
+        let contract = format!(
+            " Callers must uphold the safety contract of [`{}`].",
+            struct_name
+        );
+        (
+            quote! { unsafe },
+            quote! {
+                #[doc = " # Safety"]
+                #[doc = ""]
+                #[doc = #contract]
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    }
+}
+
+/// Builds the `where` clause holding the thread-safety bounds requested for a field's getters.
+fn getter_bounds(
+    struct_attrs: &StructAttributes,
+    attrs: &FieldAttributes,
+    field_ty: &syn::Type,
+) -> proc_macro2::TokenStream {
+    let mut bounds = Vec::new();
+    if struct_attrs.send_bound && !attrs.no_send_bound {
+        bounds.push(quote! { ::std::marker::Send });
+    }
+    if struct_attrs.sync_bound {
+        bounds.push(quote! { ::std::marker::Sync });
+    }
+    if bounds.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #field_ty: #(#bounds)+* }
+    }
+}
+
+/// Names the main getter of a field, applying the struct-level `prefix` and `suffix` to the field name
+/// or its `getter_name`.
+fn getter_name(struct_attrs: &StructAttributes, field_name: &Ident) -> Ident {
+    if struct_attrs.prefix.is_none() && struct_attrs.suffix.is_none() {
+        return field_name.clone();
+    }
+    let affix = |affix: &Option<LitStr>| affix.as_ref().map_or_else(String::new, LitStr::value);
+    Ident::new(
+        &format!(
+            "{}{}{}",
+            affix(&struct_attrs.prefix),
+            field_name.to_string().trim_start_matches("r#"),
+            affix(&struct_attrs.suffix)
+        ),
+        field_name.span(),
+    )
+}
+
+/// Builds a per-field method name such as `set_<field>` or `<field>_keys`, dropping the `r#` of raw identifiers.
+fn affixed_name(prefix: &str, field_name: &Ident, suffix: &str) -> Ident {
+    Ident::new(
+        &format!(
+            "{}{}{}",
+            prefix,
+            field_name.to_string().trim_start_matches("r#"),
+            suffix
+        ),
+        field_name.span(),
+    )
+}
+
+/// Builds the `field_access_hook` call emitted at the start of every generated getter.
+fn field_access_hook(
+    struct_attrs: &StructAttributes,
+    struct_name: &Ident,
+    field: &impl quote::ToTokens,
+) -> proc_macro2::TokenStream {
+    match &struct_attrs.field_access_hook {
+        Some(hook) => quote! { #hook(stringify!(#struct_name), stringify!(#field)); },
+        None => quote! {},
     }
 }
 
@@ -317,28 +3525,92 @@ struct FieldAttributes {
     use_as_ref: bool,
     generate_mut: bool,
     skip_getter: bool,
-    custom_logic: Option<LitStr>,
+    custom_logic: Option<syn::Path>,
     custom_return_type: Option<syn::Type>,
     copy: bool,
     clone: bool,
+    const_eval: bool,
+    custom_method: Option<Ident>,
+    instrument: bool,
+    map_iter: bool,
+    metrics: bool,
+    memoize_key: Option<syn::Type>,
+    parsed_cache: Option<(Ident, syn::Type)>,
+    fallible: bool,
+    const_name: Option<Ident>,
+    duration_parts: bool,
+    pipe: Option<Vec<syn::Path>>,
+    doc_example: Option<LitStr>,
+    cow_if: Option<(syn::Path, syn::Path)>,
+    borrow_impl: Option<Option<syn::Type>>,
+    type_alias: Option<Ident>,
+    forward_attrs: Vec<Ident>,
+    validate: Option<syn::Path>,
+    stable_hash: Option<Option<syn::Path>>,
+    safe_getter: bool,
+    iter_cloned: bool,
+    no_send_bound: bool,
+    set_if_none: bool,
+    env: Option<LitStr>,
+    sorted_view: bool,
+    exclusive: bool,
+    version: Option<LitStr>,
+    deprecated_since: Option<LitStr>,
+    read_getter: bool,
+    atomic_ordering: Option<Ident>,
+    new_default: Option<syn::Expr>,
+    map_result: Option<syn::Path>,
+    debug_checked: Option<syn::Expr>,
+    force: bool,
+    range: Option<syn::Expr>,
+    format: Option<syn::Lit>,
+    shared_clone: Option<Option<LitStr>>,
+    flags: Vec<(Ident, syn::LitInt)>,
+    clamp: Option<syn::ExprRange>,
+    auto_now: bool,
+    expect_some: Option<LitStr>,
+    update: bool,
+    downcast: Option<syn::Type>,
+    builder_validate: Option<syn::Path>,
+    project_vec: Option<syn::Path>,
+    auto_id: bool,
+    first: bool,
+    last: bool,
+    semver: bool,
+    rfc3339: bool,
+    logic_by_value: bool,
+    unique: bool,
+    getter_name: Option<LitStr>,
+    visibility: Option<syn::Visibility>,
+    use_clone: bool,
+    raw_parts: bool,
+    include_getter: bool,
+    set: bool,
+    skip_set: bool,
+    as_enum: Option<syn::Path>,
+    as_option: bool,
+    into_arg: bool,
+    get_slice: bool,
+    heap_size: bool,
 }
 
 /// Parses attributes applied to struct fields and returns a `FieldAttributes` instance.
 ///
 /// This function reads through the provided attributes and sets flags in `FieldAttributes`
-/// based on the attributes found.
-fn parse_field_attributes(attrs: &[Attribute]) -> FieldAttributes {
+/// based on the attributes found. Attributes expecting a string literal fail with an error
+/// pointing at the offending value.
+fn parse_field_attributes(attrs: &[Attribute]) -> syn::Result<FieldAttributes> {
     attrs
         .iter()
-        .fold(FieldAttributes::default(), |mut acc, attr| {
+        .try_fold(FieldAttributes::default(), |mut acc, attr| {
             match attr.meta {
                 syn::Meta::NameValue(ref nv) if nv.path.is_ident(RETURN_TYPE) => {
                     if let syn::Expr::Lit(ref value) = nv.value {
                         match &value.lit {
                             syn::Lit::Str(ref lit) => {
-                                acc.custom_return_type = lit.parse().ok();
+                                acc.custom_return_type = Some(parse_lit(lit)?);
                             }
-                            _ => todo!(),
+                            lit => return Err(expected_string(&nv.path, lit)),
                         }
                     }
                 }
@@ -346,19 +3618,431 @@ fn parse_field_attributes(attrs: &[Attribute]) -> FieldAttributes {
                 syn::Meta::Path(ref path) if path.is_ident(USE_AS_DEREF) => acc.use_as_deref = true,
                 syn::Meta::Path(ref path) if path.is_ident(COPY) => acc.copy = true,
                 syn::Meta::Path(ref path) if path.is_ident(CLONE) => acc.clone = true,
+                syn::Meta::Path(ref path) if path.is_ident(COPY_CLONE) => acc.use_clone = true,
+                syn::Meta::Path(ref path) if path.is_ident(RAW_PARTS) => acc.raw_parts = true,
+                syn::Meta::Path(ref path) if path.is_ident(INCLUDE_GETTER) => acc.include_getter = true,
+                syn::Meta::Path(ref path) if path.is_ident(SET) => acc.set = true,
+                syn::Meta::Path(ref path) if path.is_ident(SKIP_SET) => acc.skip_set = true,
+                syn::Meta::Path(ref path) if path.is_ident(GET_OPTION) => acc.as_option = true,
+                syn::Meta::Path(ref path) if path.is_ident(INTO_ARG) => acc.into_arg = true,
+                syn::Meta::Path(ref path) if path.is_ident(GET_SLICE) => acc.get_slice = true,
+                syn::Meta::Path(ref path) if path.is_ident(HEAP_SIZE) => acc.heap_size = true,
                 syn::Meta::Path(ref path) if path.is_ident(USE_AS_REF) => acc.use_as_ref = true,
                 syn::Meta::Path(ref path) if path.is_ident(GET_MUT) => acc.generate_mut = true,
                 syn::Meta::Path(ref path) if path.is_ident(SKIP_GETTER) => acc.skip_getter = true,
+                syn::Meta::Path(ref path) if path.is_ident(MAP_ITER) => acc.map_iter = true,
+                syn::Meta::Path(ref path) if path.is_ident(READ_GETTER) => acc.read_getter = true,
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_EXCLUSIVE) => acc.exclusive = true,
+                syn::Meta::Path(ref path) if path.is_ident(SORTED_VIEW) => acc.sorted_view = true,
+                syn::Meta::Path(ref path) if path.is_ident(SET_IF_NONE) => acc.set_if_none = true,
+                syn::Meta::Path(ref path) if path.is_ident(FORCE) => acc.force = true,
+                syn::Meta::Path(ref path) if path.is_ident(AUTO_NOW) => acc.auto_now = true,
+                syn::Meta::Path(ref path) if path.is_ident(UPDATE) => acc.update = true,
+                syn::Meta::Path(ref path) if path.is_ident(AUTO_ID) => acc.auto_id = true,
+                syn::Meta::Path(ref path) if path.is_ident(FIRST) => acc.first = true,
+                syn::Meta::Path(ref path) if path.is_ident(LAST) => acc.last = true,
+                syn::Meta::Path(ref path) if path.is_ident(SEMVER) => acc.semver = true,
+                syn::Meta::Path(ref path) if path.is_ident(RFC3339) => acc.rfc3339 = true,
+                syn::Meta::Path(ref path) if path.is_ident(UNIQUE) => acc.unique = true,
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_LOGIC_OWNED) => acc.logic_by_value = true,
+                syn::Meta::Path(ref path) if path.is_ident(NO_SEND_BOUND) => acc.no_send_bound = true,
+                syn::Meta::Path(ref path) if path.is_ident(ITER_CLONED) => acc.iter_cloned = true,
+                syn::Meta::Path(ref path) if path.is_ident(SAFE_GETTER) => acc.safe_getter = true,
+                syn::Meta::Path(ref path) if path.is_ident(DURATION_PARTS) => {
+                    acc.duration_parts = true
+                }
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_FALLIBLE) => acc.fallible = true,
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_METRICS) => acc.metrics = true,
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_INSTRUMENT) => {
+                    acc.instrument = true
+                }
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_CONST_EVAL) => {
+                    acc.const_eval = true
+                }
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_MEMOIZE) => {
+                    return Err(missing_key(path, "key_type"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(GETTER_MEMOIZE) => {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("key_type") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            acc.memoize_key = Some(parse_lit(&lit)?);
+                        } else {
+                            return Err(meta.error("unsupported `getter_memoize` option, expected `key_type`"));
+                        }
+                        Ok(())
+                    })?;
+                    if acc.memoize_key.is_none() {
+                        return Err(missing_key(&list.path, "key_type"));
+                    }
+                }
+                syn::Meta::Path(ref path) if path.is_ident(PARSED_CACHE) => {
+                    return Err(missing_key(path, "into"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(PARSED_CACHE) => {
+                    let (mut into, mut parsed_ty) = (None, None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("into") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            into = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("as") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            parsed_ty = Some(parse_lit(&lit)?);
+                        } else {
+                            return Err(meta.error(
+                                "unsupported `parsed_cache` option, expected `into` or `as`",
+                            ));
+                        }
+                        Ok(())
+                    })?;
+                    acc.parsed_cache = Some((
+                        into.ok_or_else(|| missing_key(&list.path, "into"))?,
+                        parsed_ty.ok_or_else(|| missing_key(&list.path, "as"))?,
+                    ));
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_CONST_NAME) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.const_name = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_PIPE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => {
+                                acc.pipe = lit
+                                    .parse_with(
+                                        syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                                    )
+                                    .map(|paths| Some(paths.into_iter().collect()))?
+                            }
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_MAP_RESULT) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.map_result = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::Path(ref path) if path.is_ident(SHARED_CLONE) => acc.shared_clone = Some(None),
+                syn::Meta::List(ref list) if list.path.is_ident(SHARED_CLONE) => {
+                    acc.shared_clone = Some(None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("feature") {
+                            acc.shared_clone = Some(Some(meta.value()?.parse()?));
+                        }
+                        Ok(())
+                    })?;
+                }
+                syn::Meta::Path(ref path) if path.is_ident(FLAG) => {
+                    return Err(missing_key(path, "name"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(FLAG) => {
+                    let (mut flag_name, mut bit) = (None, None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("name") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            flag_name = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("bit") {
+                            bit = Some(meta.value()?.parse()?);
+                        } else {
+                            return Err(meta.error("unsupported `flag` option, expected `name` or `bit`"));
+                        }
+                        Ok(())
+                    })?;
+                    acc.flags.push((
+                        flag_name.ok_or_else(|| missing_key(&list.path, "name"))?,
+                        bit.ok_or_else(|| missing_key(&list.path, "bit"))?,
+                    ));
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(EXPECT_SOME) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.expect_some = Some(lit.clone()),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(PROJECT_VEC) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.project_vec = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(BUILDER_VALIDATE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.builder_validate = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(AS_ENUM) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.as_enum = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(DOWNCAST) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.downcast = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(CLAMP) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.clamp = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(FORMAT) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        acc.format = Some(value.lit.clone());
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(RANGE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.range = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(DEBUG_CHECKED) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.debug_checked = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(NEW_DEFAULT) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.new_default = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_DOC_EXAMPLE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.doc_example = Some(lit.clone()),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::Path(ref path) if path.is_ident(COW_IF) => {
+                    return Err(missing_key(path, "predicate"));
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(COW_IF) => {
+                    let (mut predicate, mut owned) = (None, None);
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("predicate") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            predicate = Some(parse_lit(&lit)?);
+                        } else if meta.path.is_ident("owned") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            owned = Some(parse_lit(&lit)?);
+                        } else {
+                            return Err(meta.error(
+                                "unsupported `cow_if` option, expected `predicate` or `owned`",
+                            ));
+                        }
+                        Ok(())
+                    })?;
+                    acc.cow_if = Some((
+                        predicate.ok_or_else(|| missing_key(&list.path, "predicate"))?,
+                        owned.ok_or_else(|| missing_key(&list.path, "owned"))?,
+                    ));
+                }
+                syn::Meta::Path(ref path) if path.is_ident(BORROW_IMPL) => {
+                    acc.borrow_impl = Some(None)
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(BORROW_IMPL) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.borrow_impl = Some(Some(parse_lit(lit)?)),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_TYPE_ALIAS) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.type_alias = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(FORWARD_ATTRS) => {
+                    attr.parse_nested_meta(|meta| {
+                        if let Some(ident) = meta.path.get_ident() {
+                            acc.forward_attrs.push(ident.clone());
+                        }
+                        Ok(())
+                    })?;
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(VALIDATE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.validate = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::Path(ref path) if path.is_ident(STABLE_HASH) => {
+                    acc.stable_hash = Some(None)
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(STABLE_HASH) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.stable_hash = Some(Some(parse_lit(lit)?)),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(ENV) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.env = Some(lit.clone()),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_VERSION) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.version = Some(lit.clone()),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_DEPRECATED_SINCE) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.deprecated_since = Some(lit.clone()),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::Path(ref path) if path.is_ident(GETTER_ATOMIC) => {
+                    acc.atomic_ordering = Some(Ident::new("Relaxed", path.span()))
+                }
+                syn::Meta::List(ref list) if list.path.is_ident(GETTER_ATOMIC) => {
+                    acc.atomic_ordering = Some(Ident::new("Relaxed", list.path.span()));
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("ordering") {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            acc.atomic_ordering = Some(parse_lit(&lit)?);
+                        }
+                        Ok(())
+                    })?;
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_METHOD) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.custom_method = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
                 syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_LOGIC) => {
                     if let syn::Expr::Lit(ref value) = nv.value {
                         match &value.lit {
-                            syn::Lit::Str(lit) => acc.custom_logic = Some(lit.clone()),
-                            _ => todo!(),
+                            syn::Lit::Str(lit) => acc.custom_logic = Some(parse_lit(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_VIS) || nv.path.is_ident(VIS) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => acc.visibility = Some(parse_visibility(lit)?),
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv)
+                    if nv.path.is_ident(GETTER_NAME) || nv.path.is_ident(RENAME) =>
+                {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => {
+                                if lit.parse::<Ident>().is_err() {
+                                    return Err(syn::Error::new_spanned(
+                                        lit,
+                                        format!("`{}` is not a valid identifier", lit.value()),
+                                    ));
+                                }
+                                acc.getter_name = Some(lit.clone());
+                            }
+                            lit => return Err(expected_string(&nv.path, lit)),
+                        }
+                    }
+                }
+                syn::Meta::NameValue(ref nv) if nv.path.is_ident(GETTER_LOGIC_COPY) => {
+                    if let syn::Expr::Lit(ref value) = nv.value {
+                        match &value.lit {
+                            syn::Lit::Str(lit) => {
+                                acc.custom_logic = Some(parse_lit(lit)?);
+                                acc.logic_by_value = true;
+                            }
+                            lit => return Err(expected_string(&nv.path, lit)),
                         }
                     }
                 }
                 _ => (),
             }
-            acc
+            Ok(acc)
         })
 }
+
+/// Parses the contents of a string literal, reporting errors, including unbalanced delimiters, on the literal.
+fn parse_lit<T: syn::parse::Parse>(lit: &LitStr) -> syn::Result<T> {
+    lit.parse().map_err(|err| syn::Error::new_spanned(lit, err))
+}
+
+/// Parses a `getter_vis` value such as `"pub(crate)"`, or `""` for private getters.
+fn parse_visibility(lit: &LitStr) -> syn::Result<syn::Visibility> {
+    lit.parse().map_err(|_| {
+        syn::Error::new_spanned(
+            lit,
+            format!(
+                "`{}` is not a valid visibility, expected e.g. \"pub\", \"pub(crate)\" or \"\"",
+                lit.value()
+            ),
+        )
+    })
+}
+
+/// Builds the error reported when a list attribute such as `#[flag(..)]` lacks a required key.
+fn missing_key(path: &syn::Path, key: &str) -> syn::Error {
+    syn::Error::new_spanned(path, format!("`{}` requires `{}`", quote! { #path }, key))
+}
+
+/// Builds the error reported when an attribute is given something other than a string literal.
+fn expected_string(path: &syn::Path, lit: &syn::Lit) -> syn::Error {
+    let name = quote! { #path }.to_string();
+    syn::Error::new_spanned(
+        lit,
+        format!(
+            "`{}` expects a string literal, e.g. `#[{} = \"...\"]`",
+            name, name
+        ),
+    )
+}
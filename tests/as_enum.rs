@@ -0,0 +1,36 @@
+use getters::Getters;
+
+#[derive(Debug, PartialEq)]
+pub enum HttpStatus {
+    Ok,
+    NotFound,
+}
+
+impl TryFrom<u16> for HttpStatus {
+    type Error = u16;
+
+    fn try_from(code: u16) -> Result<Self, u16> {
+        match code {
+            200 => Ok(HttpStatus::Ok),
+            404 => Ok(HttpStatus::NotFound),
+            other => Err(other),
+        }
+    }
+}
+
+#[derive(Getters)]
+pub struct Response {
+    #[as_enum = "HttpStatus"]
+    code: u16,
+}
+
+#[test]
+fn known_values_convert() {
+    assert_eq!(Response::new(404).http_status(), Ok(HttpStatus::NotFound));
+    assert_eq!(*Response::new(200).code(), 200);
+}
+
+#[test]
+fn conversion_errors_are_returned() {
+    assert_eq!(Response::new(418).http_status(), Err(418));
+}
@@ -0,0 +1,59 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use getters::Getters;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+async fn connect(mut session: Session) -> Session {
+    session.connected = true;
+    session
+}
+
+#[derive(Getters)]
+#[async_new = "connect"]
+pub struct Session {
+    host: String,
+    #[new_default = "Default::default"]
+    connected: bool,
+}
+
+fn non_zero(value: &u16) -> bool {
+    *value != 0
+}
+
+#[derive(Debug, Getters)]
+#[async_new = "warm_up"]
+pub struct Pool {
+    #[validate = "non_zero"]
+    size: u16,
+}
+
+async fn warm_up(pool: Pool) -> Pool {
+    pool
+}
+
+#[test]
+fn new_awaits_the_initializer() {
+    let session = block_on(Session::new("db".to_string()));
+    assert_eq!(session.host(), "db");
+    assert!(*session.connected());
+}
+
+#[test]
+fn try_new_is_async_too() {
+    assert_eq!(*block_on(Pool::try_new(4)).unwrap().size(), 4);
+    assert_eq!(
+        block_on(Pool::try_new(0)).unwrap_err(),
+        "field `size` failed validation `non_zero`"
+    );
+}
@@ -0,0 +1,26 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Ticket {
+    #[auto_id]
+    id: u64,
+    title: String,
+}
+
+#[derive(Getters)]
+pub struct Invoice {
+    #[auto_id]
+    number: u32,
+}
+
+#[test]
+fn ids_increase_per_struct_starting_at_one() {
+    // Both structs are only constructed here, so the counters start fresh.
+    let first = Ticket::new("first".to_string());
+    let second = Ticket::new("second".to_string());
+    let invoice = Invoice::new();
+    assert_eq!(*first.id(), 1);
+    assert_eq!(*second.id(), 2);
+    assert_eq!(second.title(), "second");
+    assert_eq!(*invoice.number(), 1);
+}
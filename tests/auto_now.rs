@@ -0,0 +1,23 @@
+use std::time::{Instant, SystemTime};
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Event {
+    name: String,
+    #[auto_now]
+    created_at: SystemTime,
+    #[auto_now]
+    started: Instant,
+}
+
+#[test]
+fn timestamps_are_set_to_now() {
+    let before_system = SystemTime::now();
+    let before_instant = Instant::now();
+    let event = Event::new("boot".to_string());
+    assert_eq!(event.name(), "boot");
+    assert!(*event.created_at() >= before_system);
+    assert!(*event.started() >= before_instant);
+    assert!(*event.started() <= Instant::now());
+}
@@ -0,0 +1,29 @@
+use getters::Getters;
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+#[derive(Getters, PartialEq, Eq, Hash)]
+pub struct UserName {
+    #[borrow_impl = "str"]
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct Id {
+    #[borrow_impl]
+    value: u64,
+}
+
+#[test]
+fn map_lookup_by_borrowed_str() {
+    let users = HashSet::from([UserName::new("ada".to_string())]);
+    assert!(users.contains("ada"));
+    assert!(!users.contains("bob"));
+}
+
+#[test]
+fn borrow_defaults_to_the_field_type() {
+    let id = Id::new(7);
+    let value: &u64 = id.borrow();
+    assert_eq!(*value, 7);
+}
@@ -0,0 +1,22 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Node {
+    next: Box<Option<u32>>,
+    #[return_type = "&Option<u32>"]
+    raw: Box<Option<u32>>,
+}
+
+#[test]
+fn box_option_returns_option_ref() {
+    let node = Node::new(Box::new(Some(1)), Box::new(None));
+    let next: Option<&u32> = node.next();
+    assert_eq!(next, Some(&1));
+    assert_eq!(Node::new(Box::new(None), Box::new(None)).next(), None);
+}
+
+#[test]
+fn return_type_overrides_the_projection() {
+    let node = Node::new(Box::new(None), Box::new(Some(2)));
+    assert_eq!(node.raw(), &Some(2));
+}
@@ -0,0 +1,43 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[builder]
+#[new]
+pub struct Hybrid {
+    host: String,
+    port: u16,
+}
+
+#[derive(Getters)]
+#[builder]
+pub struct BuilderOnly {
+    port: u16,
+}
+
+#[test]
+fn new_and_builder_coexist() {
+    let from_new = Hybrid::new("localhost".to_string(), 80);
+    let built = Hybrid::builder()
+        .with_host("localhost".to_string())
+        .with_port(80)
+        .build()
+        .unwrap();
+    assert_eq!(
+        (from_new.host(), from_new.port()),
+        (built.host(), built.port())
+    );
+}
+
+#[test]
+fn build_reports_the_missing_field() {
+    let error = Hybrid::builder().with_port(80).build().err().unwrap();
+    assert!(error.contains("host"), "{}", error);
+}
+
+#[test]
+fn builder_alone_builds() {
+    assert_eq!(
+        *BuilderOnly::builder().with_port(1).build().unwrap().port(),
+        1
+    );
+}
@@ -0,0 +1,42 @@
+use getters::Getters;
+
+// A derive macro never sees the `#[derive(..)]` list it is invoked from, so `bon::Builder` has to
+// be listed separately for `Getters` to detect it.
+#[derive(Getters, bon::Builder)]
+pub struct WithBon {
+    port: u16,
+}
+
+#[derive(Getters, derive_builder::Builder)]
+pub struct WithDeriveBuilder {
+    port: u16,
+}
+
+#[derive(Getters, bon::Builder)]
+#[new_name = "with_port"]
+pub struct Renamed {
+    port: u16,
+}
+
+#[test]
+fn bon_builder_replaces_new() {
+    let config = WithBon::builder().port(80).build();
+    assert_eq!(*config.port(), 80);
+}
+
+#[test]
+fn other_builder_derives_keep_new() {
+    let config = WithDeriveBuilder::new(80);
+    assert_eq!(*config.port(), 80);
+    let built = WithDeriveBuilderBuilder::default()
+        .port(81)
+        .build()
+        .unwrap();
+    assert_eq!(*built.port(), 81);
+}
+
+#[test]
+fn new_name_keeps_constructor_with_bon() {
+    assert_eq!(*Renamed::with_port(8080).port(), 8080);
+    assert_eq!(*Renamed::builder().port(1).build().port(), 1);
+}
@@ -0,0 +1,30 @@
+use getters::Getters;
+
+fn non_zero(value: &u16) -> bool {
+    *value != 0
+}
+
+#[derive(Getters)]
+#[builder]
+pub struct Server {
+    host: String,
+    #[builder_validate = "non_zero"]
+    port: u16,
+}
+
+#[test]
+fn valid_values_build() {
+    let server = Server::builder()
+        .with_host("localhost".to_string())
+        .with_port(8080)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(*server.port(), 8080);
+}
+
+#[test]
+fn setter_rejects_invalid_values() {
+    let error = Server::builder().with_port(0).err().unwrap();
+    assert_eq!(error, "field `port` failed validation `non_zero`");
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "crc32")]
+
+use getters::Getters;
+
+#[derive(Getters)]
+#[checksum(over = "header, body")]
+pub struct Packet {
+    header: [u8; 2],
+    body: Vec<u8>,
+    sequence: u32,
+}
+
+#[test]
+fn checksum_covers_the_listed_fields_in_order() {
+    let packet = Packet::new([1, 2], vec![3, 4], 9);
+    assert_eq!(packet.checksum(), crc32fast::hash(&[1, 2, 3, 4]));
+    let reordered = Packet::new([3, 4], vec![1, 2], 9);
+    assert_ne!(packet.checksum(), reordered.checksum());
+}
+
+#[test]
+fn unlisted_fields_do_not_affect_the_checksum() {
+    let first = Packet::new([1, 2], vec![3], 1);
+    let second = Packet::new([1, 2], vec![3], 2);
+    assert_eq!(first.checksum(), second.checksum());
+}
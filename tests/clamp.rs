@@ -0,0 +1,26 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Pixel {
+    #[clamp = "0..=255"]
+    red: i32,
+    #[clamp = "0.0..=1.0"]
+    alpha: f32,
+}
+
+#[test]
+fn values_are_clamped_to_the_range() {
+    let pixel = Pixel::new(300, -0.5);
+    assert_eq!(pixel.red(), 255);
+    assert_eq!(pixel.alpha(), 0.0);
+    let pixel = Pixel::new(-4, 1.5);
+    assert_eq!(pixel.red(), 0);
+    assert_eq!(pixel.alpha(), 1.0);
+}
+
+#[test]
+fn values_in_range_are_returned_unchanged() {
+    let pixel = Pixel::new(128, 0.25);
+    assert_eq!(pixel.red(), 128);
+    assert_eq!(pixel.alpha(), 0.25);
+}
@@ -0,0 +1,27 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Config {
+    #[getter_const_name = "PORT_FIELD"]
+    port: u16,
+    host: String,
+}
+
+#[derive(Getters)]
+#[const_field_names]
+pub struct Token {
+    #[getter_const_name = "KIND"]
+    r#type: u8,
+    value: String,
+}
+
+#[test]
+fn named_constant_holds_the_field_name() {
+    assert_eq!(Config::PORT_FIELD, "port");
+}
+
+#[test]
+fn const_field_names_covers_every_field() {
+    assert_eq!(Token::KIND, "type");
+    assert_eq!(Token::VALUE, "value");
+}
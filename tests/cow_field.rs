@@ -0,0 +1,23 @@
+use getters::Getters;
+use std::borrow::Cow;
+
+#[derive(Getters)]
+pub struct Message<'a> {
+    text: Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    #[return_type = "&Cow<'a, str>"]
+    raw: Cow<'a, str>,
+}
+
+#[test]
+fn cow_getters_dereference_to_the_target() {
+    let message = Message::new(
+        Cow::Borrowed("borrowed"),
+        Cow::Owned(vec![1, 2]),
+        Cow::Owned("owned".to_string()),
+    );
+    let text: &str = message.text();
+    let bytes: &[u8] = message.bytes();
+    assert_eq!((text, bytes), ("borrowed", &[1u8, 2][..]));
+    assert!(matches!(message.raw(), Cow::Owned(_)));
+}
@@ -0,0 +1,30 @@
+use getters::Getters;
+use std::borrow::Cow;
+
+fn has_tabs(text: &str) -> bool {
+    text.contains('\t')
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', "    ")
+}
+
+#[derive(Getters)]
+pub struct Source {
+    #[cow_if(predicate = "has_tabs", owned = "expand_tabs")]
+    code: String,
+}
+
+#[test]
+fn borrows_when_the_predicate_is_false() {
+    let source = Source::new("fn main() {}".to_string());
+    assert!(matches!(source.code(), Cow::Borrowed("fn main() {}")));
+}
+
+#[test]
+fn owns_the_transformed_value_when_the_predicate_is_true() {
+    let source = Source::new("\tx".to_string());
+    let code = source.code();
+    assert!(matches!(code, Cow::Owned(_)));
+    assert_eq!(code, "    x");
+}
@@ -0,0 +1,21 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Buffer {
+    #[debug_checked = "|v: &Vec<u8>| { !v.is_empty() }"]
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn invariant_holds() {
+    let buffer = Buffer::new(vec![1, 2]);
+    assert_eq!(buffer.bytes(), &[1, 2]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "violated for `Buffer::bytes`")]
+fn invariant_violation_panics_in_debug() {
+    let buffer = Buffer::new(Vec::new());
+    let _ = buffer.bytes();
+}
@@ -0,0 +1,38 @@
+use getters::Getters;
+
+fn non_zero(value: &u16) -> bool {
+    *value != 0
+}
+
+pub struct RawConfig {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Getters)]
+#[derive_try_from = "RawConfig"]
+pub struct Config {
+    host: String,
+    #[validate = "non_zero"]
+    port: u16,
+}
+
+#[test]
+fn valid_source_converts() {
+    let raw = RawConfig {
+        host: "localhost".to_string(),
+        port: 80,
+    };
+    let config = Config::try_from(raw).unwrap();
+    assert_eq!((config.host().as_str(), *config.port()), ("localhost", 80));
+}
+
+#[test]
+fn invalid_source_reports_the_field() {
+    let raw = RawConfig {
+        host: "localhost".to_string(),
+        port: 0,
+    };
+    let error = Config::try_from(raw).unwrap_err();
+    assert_eq!(error, "field `port` failed validation `non_zero`");
+}
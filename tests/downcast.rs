@@ -0,0 +1,26 @@
+use std::any::Any;
+
+use getters::Getters;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    retries: u8,
+}
+
+#[derive(Getters)]
+pub struct Plugin {
+    #[downcast = "Config"]
+    state: Box<dyn Any>,
+}
+
+#[test]
+fn matching_type_is_returned() {
+    let plugin = Plugin::new(Box::new(Config { retries: 3 }));
+    assert_eq!(plugin.state(), Some(&Config { retries: 3 }));
+}
+
+#[test]
+fn other_types_return_none() {
+    let plugin = Plugin::new(Box::new("not a config"));
+    assert_eq!(plugin.state(), None);
+}
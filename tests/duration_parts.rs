@@ -0,0 +1,16 @@
+use getters::Getters;
+use std::time::Duration;
+
+#[derive(Getters)]
+pub struct Timeout {
+    #[duration_parts]
+    limit: Duration,
+}
+
+#[test]
+fn exposes_seconds_and_milliseconds() {
+    let timeout = Timeout::new(Duration::from_millis(2_750));
+    assert_eq!(timeout.limit_secs(), 2);
+    assert_eq!(timeout.limit_millis(), 2_750);
+    assert_eq!(timeout.limit(), &Duration::from_millis(2_750));
+}
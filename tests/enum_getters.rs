@@ -0,0 +1,91 @@
+use getters::Getters;
+use std::cell::RefCell;
+
+thread_local! {
+    static ACCESSES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(owner: &str, field: &str) {
+    ACCESSES.with(|accesses| accesses.borrow_mut().push(format!("{}.{}", owner, field)));
+}
+
+#[derive(Getters)]
+#[field_access_hook = "record"]
+pub enum Shape {
+    Circle { id: u32, radius: f64 },
+    Square { id: u32, side: f64 },
+    Empty,
+}
+
+#[derive(Getters)]
+#[skip_all]
+pub enum Partial {
+    A {
+        #[include_getter]
+        kept: u8,
+        hidden: u8,
+    },
+}
+
+#[derive(Getters)]
+#[getters(prefix = "get_")]
+pub enum Token {
+    Ident {
+        #[rename = "kind"]
+        type_: u8,
+    },
+    Literal {
+        type_: u8,
+        #[getter_name = "text"]
+        raw: String,
+    },
+}
+
+#[derive(Getters)]
+#[getter_safety = "unsafe"]
+pub enum Raw {
+    Ptr { addr: usize },
+}
+
+#[test]
+fn getters_match_the_active_variant() {
+    let circle = Shape::Circle { id: 1, radius: 2.0 };
+    assert_eq!(circle.id(), Some(&1));
+    assert_eq!(circle.radius(), Some(&2.0));
+    assert_eq!(circle.side(), None);
+    assert_eq!(Shape::Empty.id(), None);
+}
+
+#[test]
+fn access_hook_runs_for_enum_getters() {
+    let square = Shape::Square { id: 3, side: 4.0 };
+    let _ = square.side();
+    ACCESSES.with(|accesses| assert_eq!(accesses.borrow().last().unwrap(), "Shape.side"));
+}
+
+#[test]
+fn skip_all_keeps_included_fields() {
+    let partial = Partial::A { kept: 1, hidden: 2 };
+    assert_eq!(partial.kept(), Some(&1));
+    let Partial::A { hidden, .. } = partial;
+    assert_eq!(hidden, 2);
+}
+
+#[test]
+fn unsafe_getters_apply_to_enums() {
+    let raw = Raw::Ptr { addr: 8 };
+    assert_eq!(unsafe { raw.addr() }, Some(&8));
+}
+
+#[test]
+fn renamed_variant_fields_use_the_new_name() {
+    let ident = Token::Ident { type_: 1 };
+    assert_eq!(ident.get_kind(), Some(&1));
+    assert_eq!(ident.get_text(), None);
+    let literal = Token::Literal {
+        type_: 2,
+        raw: "42".to_string(),
+    };
+    assert_eq!(literal.get_kind(), Some(&2));
+    assert_eq!(literal.get_text().map(String::as_str), Some("42"));
+}
@@ -0,0 +1,37 @@
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Connection {
+    #[expect_some = "connection has no peer"]
+    peer: Option<String>,
+}
+
+#[test]
+fn present_value_is_returned_by_reference() {
+    let connection = Connection::new(Some("10.0.0.1".to_string()));
+    let peer: &String = connection.peer();
+    assert_eq!(peer, "10.0.0.1");
+}
+
+#[test]
+fn missing_value_panics_at_the_caller() {
+    let location = Arc::new(Mutex::new(None));
+    let recorded = Arc::clone(&location);
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let panic_location = info.location().unwrap();
+        *recorded.lock().unwrap() = Some((panic_location.file().to_string(), info.to_string()));
+    }));
+    let result = panic::catch_unwind(|| {
+        Connection::new(None).peer();
+    });
+    panic::set_hook(previous);
+
+    assert!(result.is_err());
+    let (file, message) = location.lock().unwrap().take().unwrap();
+    assert_eq!(file, file!());
+    assert!(message.contains("connection has no peer"));
+}
@@ -0,0 +1,47 @@
+use getters::Getters;
+use std::cell::RefCell;
+
+thread_local! {
+    static ACCESSES: RefCell<Vec<(&'static str, &'static str)>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(owner: &'static str, field: &'static str) {
+    ACCESSES.with(|accesses| accesses.borrow_mut().push((owner, field)));
+}
+
+fn take_accesses() -> Vec<(&'static str, &'static str)> {
+    ACCESSES.with(|accesses| accesses.take())
+}
+
+#[derive(Getters)]
+#[field_access_hook = "record"]
+pub struct Config {
+    #[get_mut]
+    port: u16,
+    host: String,
+}
+
+#[derive(Getters)]
+#[field_access_hook = "record"]
+pub struct Pair(u8, u8);
+
+#[test]
+fn hook_runs_on_every_getter_access() {
+    let mut config = Config::new(80, "localhost".to_string());
+    take_accesses();
+    assert_eq!(*config.port(), 80);
+    assert_eq!(config.host(), "localhost");
+    *config.port_mut() += 1;
+    assert_eq!(
+        take_accesses(),
+        [("Config", "port"), ("Config", "host"), ("Config", "port")]
+    );
+}
+
+#[test]
+fn hook_receives_tuple_field_indices() {
+    let pair = Pair::new(1, 2);
+    take_accesses();
+    assert_eq!(*pair.get_1(), 2);
+    assert_eq!(take_accesses(), [("Pair", "1")]);
+}
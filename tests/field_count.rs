@@ -0,0 +1,25 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[field_count]
+pub struct Pixel {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+#[derive(Getters)]
+#[field_count]
+pub struct Pair(u8, u8);
+
+#[test]
+fn constant_counts_the_fields() {
+    assert_eq!(Pixel::FIELD_COUNT, 3);
+    assert_eq!(Pair::FIELD_COUNT, 2);
+}
+
+#[test]
+fn constant_is_usable_in_const_contexts() {
+    const CHANNELS: [u8; Pixel::FIELD_COUNT] = [0; Pixel::FIELD_COUNT];
+    assert_eq!(CHANNELS.len(), 3);
+}
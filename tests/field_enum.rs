@@ -0,0 +1,17 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[field_enum]
+pub struct Config {
+    host_name: String,
+    port: u16,
+    r#type: u8,
+}
+
+#[test]
+fn variants_name_the_fields() {
+    assert_eq!(ConfigField::HostName.name(), "host_name");
+    assert_eq!(ConfigField::Port.name(), "port");
+    assert_eq!(ConfigField::Type.name(), "type");
+    assert_ne!(ConfigField::Port, ConfigField::Type);
+}
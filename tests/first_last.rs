@@ -0,0 +1,33 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Route<'a> {
+    #[first]
+    #[last]
+    stops: Vec<String>,
+    #[first]
+    corners: [u8; 4],
+    #[last]
+    waypoints: &'a [u32],
+}
+
+#[test]
+fn ends_of_each_sequence() {
+    let route = Route::new(
+        vec!["home".to_string(), "work".to_string()],
+        [1, 2, 3, 4],
+        &[7, 8, 9],
+    );
+    assert_eq!(route.stops_first().map(String::as_str), Some("home"));
+    assert_eq!(route.stops_last().map(String::as_str), Some("work"));
+    assert_eq!(route.corners_first(), Some(&1));
+    assert_eq!(route.waypoints_last(), Some(&9));
+}
+
+#[test]
+fn empty_sequences_return_none() {
+    let route = Route::new(Vec::new(), [0; 4], &[]);
+    assert_eq!(route.stops_first(), None);
+    assert_eq!(route.stops_last(), None);
+    assert_eq!(route.waypoints_last(), None);
+}
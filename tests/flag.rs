@@ -0,0 +1,24 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Permissions {
+    #[flag(name = "readable", bit = 0)]
+    #[flag(name = "writable", bit = 1)]
+    #[flag(name = "executable", bit = 7)]
+    bits: u8,
+}
+
+#[test]
+fn each_flag_tests_its_bit() {
+    let permissions = Permissions::new(0b1000_0001);
+    assert!(permissions.readable());
+    assert!(!permissions.writable());
+    assert!(permissions.executable());
+    assert_eq!(*permissions.bits(), 0b1000_0001);
+}
+
+#[test]
+fn no_flags_set() {
+    let permissions = Permissions::new(0);
+    assert!(!permissions.readable() && !permissions.writable() && !permissions.executable());
+}
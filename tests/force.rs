@@ -0,0 +1,32 @@
+#![cfg(feature = "once_cell")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use getters::Getters;
+use once_cell::sync::Lazy;
+
+static INITIALIZED: AtomicUsize = AtomicUsize::new(0);
+
+fn load_table() -> Vec<u32> {
+    INITIALIZED.fetch_add(1, Ordering::SeqCst);
+    vec![1, 2, 3]
+}
+
+#[derive(Getters)]
+#[skip_new]
+pub struct Tables {
+    #[force]
+    primes: Lazy<Vec<u32>>,
+}
+
+#[test]
+fn first_access_initializes_once() {
+    let tables = Tables {
+        primes: Lazy::new(load_table),
+    };
+    assert_eq!(INITIALIZED.load(Ordering::SeqCst), 0);
+    let primes: &Vec<u32> = tables.primes();
+    assert_eq!(primes, &[1, 2, 3]);
+    assert_eq!(tables.primes().len(), 3);
+    assert_eq!(INITIALIZED.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,22 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Reading {
+    #[format = "{:.2}"]
+    celsius: f64,
+    #[format = "#{:04}"]
+    sensor: u32,
+}
+
+#[test]
+fn fields_are_formatted_with_the_spec() {
+    let reading = Reading::new(21.456, 7);
+    assert_eq!(reading.celsius_fmt(), "21.46");
+    assert_eq!(reading.sensor_fmt(), "#0007");
+}
+
+#[test]
+fn plain_getters_are_kept() {
+    let reading = Reading::new(21.456, 7);
+    assert_eq!(*reading.sensor(), 7);
+}
@@ -0,0 +1,26 @@
+// `missing_docs` checks that the field documentation reaches the getters.
+#![deny(missing_docs)]
+//! Getters with attributes forwarded from their fields.
+
+use getters::Getters;
+
+/// A configuration.
+#[derive(Getters)]
+#[skip_new]
+pub struct Config {
+    /// The port to listen on.
+    #[forward_attrs(doc)]
+    port: u16,
+    #[cfg_attr(test, doc = "The host, documented in test builds only.")]
+    #[forward_attrs(doc)]
+    host: String,
+}
+
+#[test]
+fn forwarded_getters_work() {
+    let config = Config {
+        port: 80,
+        host: "localhost".to_string(),
+    };
+    assert_eq!((*config.port(), config.host().as_str()), (80, "localhost"));
+}
@@ -0,0 +1,71 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use getters::Getters;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[derive(Getters)]
+#[free_new = "make_point"]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Getters)]
+#[free_new = "make_wrapper"]
+pub struct Wrapper<T: Clone> {
+    inner: T,
+}
+
+#[test]
+fn free_function_matches_new() {
+    let point = make_point(1, 2);
+    assert_eq!((*point.x(), *point.y()), (1, 2));
+    let other = Point::new(1, 2);
+    assert_eq!((other.x(), other.y()), (point.x(), point.y()));
+}
+
+#[test]
+fn free_function_carries_generics() {
+    let wrapper: Wrapper<String> = make_wrapper("inner".to_string());
+    assert_eq!(wrapper.inner(), "inner");
+}
+
+async fn connect(mut session: Session) -> Session {
+    session.connected = true;
+    session
+}
+
+#[derive(Getters)]
+#[async_new = "connect"]
+#[free_new = "open_session"]
+pub struct Session {
+    host: String,
+    #[new_default = "Default::default"]
+    connected: bool,
+}
+
+// Like `new`, the free constructor is skipped for a struct that cannot be built by value.
+#[derive(Getters)]
+#[free_new = "make_bytes"]
+pub struct Bytes {
+    len: usize,
+    data: [u8],
+}
+
+#[test]
+fn free_function_awaits_async_new() {
+    let session = block_on(open_session("localhost".to_string()));
+    assert_eq!(session.host(), "localhost");
+    assert!(*session.connected());
+}
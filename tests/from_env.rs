@@ -0,0 +1,50 @@
+use getters::Getters;
+
+#[derive(Debug, Getters)]
+#[from_env = "GETTERS_TEST_APP_"]
+pub struct Config {
+    host: String,
+    port: u16,
+    #[env = "GETTERS_TEST_DEBUG"]
+    debug: bool,
+}
+
+#[derive(Debug, Getters)]
+#[from_env = "GETTERS_TEST_MISSING_"]
+pub struct Missing {
+    port: u16,
+}
+
+#[derive(Debug, Getters)]
+#[from_env = "GETTERS_TEST_INVALID_"]
+pub struct Invalid {
+    port: u16,
+}
+
+#[test]
+fn reads_prefixed_and_overridden_variables() {
+    std::env::set_var("GETTERS_TEST_APP_HOST", "localhost");
+    std::env::set_var("GETTERS_TEST_APP_PORT", "8080");
+    std::env::set_var("GETTERS_TEST_DEBUG", "true");
+    let config = Config::from_env().unwrap();
+    assert_eq!(config.host(), "localhost");
+    assert_eq!(*config.port(), 8080);
+    assert!(*config.debug());
+}
+
+#[test]
+fn missing_variable_is_an_error() {
+    assert_eq!(
+        Missing::from_env().unwrap_err(),
+        "environment variable `GETTERS_TEST_MISSING_PORT` is not set or not unicode"
+    );
+}
+
+#[test]
+fn unparsable_variable_is_an_error() {
+    std::env::set_var("GETTERS_TEST_INVALID_PORT", "eighty");
+    assert_eq!(
+        Invalid::from_env().unwrap_err(),
+        "environment variable `GETTERS_TEST_INVALID_PORT` could not be parsed"
+    );
+}
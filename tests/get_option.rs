@@ -0,0 +1,24 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Contact {
+    #[get_option]
+    email: Option<String>,
+    #[get_option]
+    name: String,
+}
+
+#[test]
+fn option_fields_are_borrowed_inside() {
+    let contact = Contact::new(Some("ada@example.com".to_string()), "Ada".to_string());
+    let email: Option<&String> = contact.email();
+    assert_eq!(email.map(String::as_str), Some("ada@example.com"));
+    assert_eq!(Contact::new(None, String::new()).email(), None);
+}
+
+#[test]
+fn plain_fields_are_wrapped_in_some() {
+    let contact = Contact::new(None, "Ada".to_string());
+    let name: Option<&String> = contact.name();
+    assert_eq!(name.map(String::as_str), Some("Ada"));
+}
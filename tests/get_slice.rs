@@ -0,0 +1,19 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Samples {
+    #[get_slice]
+    values: Vec<i16>,
+    #[get_slice]
+    window: [i16; 3],
+}
+
+#[test]
+fn sequences_are_exposed_as_slices() {
+    let samples = Samples::new(vec![1, -2], [3, 4, 5]);
+    let values: &[i16] = samples.values();
+    let window: &[i16] = samples.window();
+    assert_eq!(values, [1, -2]);
+    assert_eq!(window.len(), 3);
+    assert_eq!(window[1..], [4, 5]);
+}
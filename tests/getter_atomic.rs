@@ -0,0 +1,20 @@
+use getters::Getters;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+#[derive(Getters)]
+pub struct Stats {
+    #[getter_atomic]
+    hits: AtomicU32,
+    #[getter_atomic(ordering = "SeqCst")]
+    ready: AtomicBool,
+}
+
+#[test]
+fn getters_load_the_primitive() {
+    let stats = Stats::new(AtomicU32::new(1), AtomicBool::new(false));
+    stats.hits.fetch_add(2, Ordering::Relaxed);
+    stats.ready.store(true, Ordering::SeqCst);
+    let hits: u32 = stats.hits();
+    let ready: bool = stats.ready();
+    assert_eq!((hits, ready), (3, true));
+}
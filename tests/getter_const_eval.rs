@@ -0,0 +1,17 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Config {
+    #[copy]
+    #[getter_const_eval]
+    port: u16,
+}
+
+const CONFIG: Config = Config { port: 8080 };
+const PORT: u16 = CONFIG.port();
+
+#[test]
+fn const_getter_is_usable_in_const_context() {
+    assert_eq!(PORT, 8080);
+    assert_eq!(Config::new(1).port(), 1);
+}
@@ -0,0 +1,18 @@
+// `missing_docs` checks that the example is attached to the getter as documentation.
+#![deny(missing_docs)]
+//! Getters documented with `getter_doc_example`.
+
+use getters::Getters;
+
+/// A configuration.
+#[derive(Getters)]
+#[skip_new]
+pub struct Config {
+    #[getter_doc_example = "fn print_port(config: &Config) {\n    println!(\"{}\", config.port());\n}"]
+    port: u16,
+}
+
+#[test]
+fn documented_getter_works() {
+    assert_eq!(*Config { port: 8 }.port(), 8);
+}
@@ -0,0 +1,38 @@
+use getters::Getters;
+
+fn reenter(value: &u8) -> u8 {
+    REENTER.with(|reenter| {
+        if let Some(counter) = reenter.take() {
+            // Calls the getter again while it is still running.
+            let _ = counter.value();
+        }
+    });
+    *value
+}
+
+thread_local! {
+    static REENTER: std::cell::Cell<Option<&'static Counter>> = const { std::cell::Cell::new(None) };
+}
+
+#[derive(Getters)]
+pub struct Counter {
+    #[getter_exclusive]
+    #[getter_logic = "reenter"]
+    value: u8,
+}
+
+#[test]
+fn sequential_calls_are_allowed() {
+    let counter = Counter::new(1);
+    assert_eq!(counter.value(), 1);
+    assert_eq!(counter.value(), 1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "reentrant call to `Counter::value`")]
+fn reentrant_calls_panic_in_debug() {
+    let counter: &'static Counter = Box::leak(Box::new(Counter::new(2)));
+    REENTER.with(|reenter| reenter.set(Some(counter)));
+    counter.value();
+}
@@ -0,0 +1,40 @@
+use getters::Getters;
+
+fn double(value: &u32) -> u32 {
+    value * 2
+}
+
+#[derive(Getters)]
+pub struct Plain {
+    #[getter_fallible]
+    port: u16,
+    #[getter_fallible]
+    r#type: u8,
+    #[getter_fallible]
+    #[skip_getter]
+    #[getter_logic = "double"]
+    hidden: u32,
+}
+
+#[derive(Getters)]
+#[getters(prefix = "get_")]
+pub struct Prefixed {
+    #[getter_fallible]
+    #[rename = "kind"]
+    r#type: u8,
+}
+
+#[test]
+fn try_getters_wrap_the_getter_result() {
+    let plain = Plain::new(80, 1, 21);
+    assert_eq!(plain.try_port(), Some(&80));
+    assert_eq!(plain.try_type(), Some(&1));
+    assert_eq!(plain.try_hidden(), Some(42));
+}
+
+#[test]
+fn try_getters_follow_renames_and_prefix() {
+    let prefixed = Prefixed::new(3);
+    assert_eq!(prefixed.get_kind(), &3);
+    assert_eq!(prefixed.try_get_kind(), Some(&3));
+}
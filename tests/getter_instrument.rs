@@ -0,0 +1,49 @@
+#![cfg(feature = "tracing")]
+
+use getters::Getters;
+use std::sync::{Arc, Mutex};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Clone, Default)]
+struct SpanNames(Arc<Mutex<Vec<&'static str>>>);
+
+impl Subscriber for SpanNames {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut names = self.0.lock().unwrap();
+        names.push(span.metadata().name());
+        Id::from_u64(names.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[derive(Getters)]
+pub struct Config {
+    #[getter_instrument]
+    port: u16,
+    host: String,
+}
+
+#[test]
+fn instrumented_getter_opens_a_named_span() {
+    let names = SpanNames::default();
+    let config = Config::new(80, "localhost".to_string());
+    tracing::subscriber::with_default(names.clone(), || {
+        assert_eq!(*config.port(), 80);
+        assert_eq!(config.host(), "localhost");
+    });
+    assert_eq!(*names.0.lock().unwrap(), ["port_getter"]);
+}
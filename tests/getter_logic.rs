@@ -0,0 +1,81 @@
+use getters::Getters;
+
+fn shout(text: &str) -> String {
+    text.to_uppercase()
+}
+
+fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+fn doubled(value: &i64) -> i64 {
+    value * 2
+}
+
+#[derive(Getters)]
+pub struct Message {
+    #[getter_logic = "shout"]
+    body: String,
+    #[getter_logic = "word_count"]
+    #[return_type = "usize"]
+    subject: String,
+    #[getter_logic = "doubled"]
+    priority: i64,
+}
+
+#[test]
+fn return_type_defaults_to_the_field_type() {
+    let message = Message::new("hello there".to_string(), "hi".to_string(), 3);
+    let body: String = message.body();
+    assert_eq!(body, "HELLO THERE");
+    let priority: i64 = message.priority();
+    assert_eq!(priority, 6);
+}
+
+#[test]
+fn return_type_overrides_the_default() {
+    let message = Message::new(String::new(), "two words".to_string(), 0);
+    let count: usize = message.subject();
+    assert_eq!(count, 2);
+}
+
+pub struct Payload {
+    bytes: Vec<u8>,
+}
+
+fn payload_len(payload: &Payload) -> usize {
+    payload.bytes.len()
+}
+
+#[derive(Getters)]
+pub struct Frame {
+    #[getter_logic = "payload_len"]
+    #[return_type = "usize"]
+    payload: Payload,
+}
+
+#[test]
+fn non_copy_fields_are_passed_by_reference() {
+    let frame = Frame::new(Payload {
+        bytes: vec![1, 2, 3],
+    });
+    assert_eq!(frame.payload(), 3);
+    assert_eq!(frame.payload(), 3);
+}
+
+fn byte_len(bytes: &[u8]) -> usize {
+    bytes.len()
+}
+
+#[derive(Getters)]
+pub struct Packet {
+    #[getter_logic = "byte_len"]
+    #[return_type = "usize"]
+    body: Vec<u8>,
+}
+
+#[test]
+fn field_references_deref_coerce_to_the_logic_argument() {
+    let packet = Packet::new(vec![1, 2, 3, 4]);
+    assert_eq!(packet.body(), 4);
+}
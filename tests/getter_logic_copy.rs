@@ -0,0 +1,27 @@
+use getters::Getters;
+
+fn celsius(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.0) / 1.8
+}
+
+fn is_even(value: u32) -> bool {
+    value.is_multiple_of(2)
+}
+
+#[derive(Getters)]
+pub struct Sensor {
+    #[getter_logic_copy = "celsius"]
+    reading: f64,
+    #[getter_logic = "is_even"]
+    #[getter_logic_owned]
+    #[return_type = "bool"]
+    sample: u32,
+}
+
+#[test]
+fn copy_fields_are_passed_by_value() {
+    let sensor = Sensor::new(212.0, 4);
+    assert_eq!(sensor.reading(), 100.0);
+    assert!(sensor.sample());
+    assert!(!Sensor::new(32.0, 3).sample());
+}
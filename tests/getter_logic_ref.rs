@@ -0,0 +1,54 @@
+use getters::Getters;
+
+fn first_word(text: &str) -> &str {
+    text.split(' ').next().unwrap_or_default()
+}
+
+fn copy_str<'a>(text: &&'a str) -> &'a str {
+    text
+}
+
+#[derive(Getters)]
+pub struct Owned {
+    #[getter_logic = "first_word"]
+    #[return_type = "&str"]
+    text: String,
+}
+
+#[derive(Getters)]
+pub struct Borrowed<'a> {
+    #[getter_logic = "copy_str"]
+    #[return_type = "&'a str"]
+    text: &'a str,
+}
+
+#[derive(Getters)]
+pub struct NamedS<'s> {
+    #[getter_logic = "first_word"]
+    #[return_type = "&str"]
+    text: String,
+    #[skip_getter]
+    _marker: &'s (),
+}
+
+#[test]
+fn reference_result_borrows_self() {
+    let owned = Owned::new("hello world".to_string());
+    assert_eq!(owned.text(), "hello");
+}
+
+#[test]
+fn struct_lifetime_is_not_redeclared() {
+    let source = String::from("borrowed text");
+    let text = {
+        let borrowed = Borrowed::new(&source);
+        borrowed.text()
+    };
+    assert_eq!(text, "borrowed text");
+}
+
+#[test]
+fn fresh_lifetime_avoids_struct_lifetime_names() {
+    let named = NamedS::new("fresh name".to_string(), &());
+    assert_eq!(named.text(), "fresh");
+}
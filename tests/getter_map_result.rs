@@ -0,0 +1,28 @@
+use getters::Getters;
+
+#[derive(Debug, PartialEq)]
+pub struct BadPort(String);
+
+fn parse_port(text: &str) -> Result<u16, BadPort> {
+    text.parse().map_err(|_| BadPort(text.to_string()))
+}
+
+#[derive(Getters)]
+pub struct Listener {
+    #[getter_map_result = "parse_port"]
+    #[return_type = "Result<u16, BadPort>"]
+    port: String,
+}
+
+#[test]
+fn transform_result_is_returned() {
+    assert_eq!(Listener::new("8080".to_string()).port(), Ok(8080));
+}
+
+#[test]
+fn transform_error_is_returned() {
+    assert_eq!(
+        Listener::new("http".to_string()).port(),
+        Err(BadPort("http".to_string()))
+    );
+}
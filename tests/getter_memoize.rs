@@ -0,0 +1,31 @@
+use getters::Getters;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+thread_local! {
+    static CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn greeting(prefix: &String, name: String) -> String {
+    CALLS.with(|calls| calls.set(calls.get() + 1));
+    format!("{} {}", prefix, name)
+}
+
+#[derive(Getters)]
+pub struct Greeter {
+    #[getter_logic = "greeting"]
+    #[getter_memoize(key_type = "String")]
+    prefix: String,
+    #[skip_getter]
+    prefix_cache: RefCell<HashMap<String, String>>,
+}
+
+#[test]
+fn owned_keys_are_computed_once() {
+    let greeter = Greeter::new("hello".to_string(), RefCell::default());
+    assert_eq!(greeter.prefix("world".to_string()), "hello world");
+    assert_eq!(greeter.prefix("world".to_string()), "hello world");
+    assert_eq!(greeter.prefix("there".to_string()), "hello there");
+    assert_eq!(CALLS.with(Cell::get), 2);
+    assert_eq!(greeter.prefix_cache.borrow().len(), 2);
+}
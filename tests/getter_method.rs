@@ -0,0 +1,23 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Label {
+    #[getter_method = "to_uppercase"]
+    text: String,
+    #[getter_method = "len"]
+    #[return_type = "usize"]
+    items: Vec<u8>,
+}
+
+#[test]
+fn method_result_keeps_the_field_type() {
+    let label = Label::new("hello".to_string(), vec![1, 2]);
+    let text: String = label.text();
+    assert_eq!(text, "HELLO");
+}
+
+#[test]
+fn return_type_overrides_the_method_result() {
+    let label = Label::new(String::new(), vec![1, 2, 3]);
+    assert_eq!(label.items(), 3);
+}
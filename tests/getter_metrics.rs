@@ -0,0 +1,32 @@
+#![cfg(feature = "metrics")]
+
+use getters::Getters;
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+#[derive(Getters)]
+pub struct Config {
+    #[getter_metrics]
+    port: u16,
+    host: String,
+}
+
+#[test]
+fn getter_increments_the_access_counter() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    let config = Config::new(80, "localhost".to_string());
+    metrics::with_local_recorder(&recorder, || {
+        config.port();
+        config.port();
+        config.host();
+    });
+    let counters: Vec<_> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| (key.key().name().to_string(), value))
+        .collect();
+    assert_eq!(counters.len(), 1);
+    assert_eq!(counters[0].0, "Config.port.access_count");
+    assert!(matches!(counters[0].1, DebugValue::Counter(2)));
+}
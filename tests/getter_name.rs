@@ -0,0 +1,26 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Token {
+    #[getter_name = "kind"]
+    #[get_mut]
+    type_: u8,
+    #[getter_name = "text"]
+    #[set]
+    raw_text: String,
+}
+
+#[test]
+fn getters_use_the_custom_name() {
+    let mut token = Token::new(1, "fn".to_string());
+    *token.kind_mut() = 2;
+    assert_eq!(*token.kind(), 2);
+    assert_eq!(token.text(), "fn");
+}
+
+#[test]
+fn setters_keep_the_field_name() {
+    let mut token = Token::new(1, "fn".to_string());
+    token.set_raw_text("let".to_string());
+    assert_eq!(token.text(), "let");
+}
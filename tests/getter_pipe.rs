@@ -0,0 +1,26 @@
+use getters::Getters;
+
+fn trimmed(text: &str) -> &str {
+    text.trim()
+}
+
+fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+fn doubled(count: usize) -> usize {
+    count * 2
+}
+
+#[derive(Getters)]
+pub struct Document {
+    #[getter_pipe = "trimmed, word_count, doubled"]
+    #[return_type = "usize"]
+    body: String,
+}
+
+#[test]
+fn functions_apply_left_to_right() {
+    let document = Document::new("  two words  ".to_string());
+    assert_eq!(document.body(), 4);
+}
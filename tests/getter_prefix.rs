@@ -0,0 +1,28 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getters(prefix = "get_")]
+pub struct Prefixed {
+    #[get_mut]
+    count: u32,
+}
+
+#[derive(Getters)]
+pub struct Plain {
+    #[get_mut]
+    count: u32,
+}
+
+#[test]
+fn prefix_applies_to_getters_and_mutable_getters() {
+    let mut prefixed = Prefixed::new(1);
+    *prefixed.get_count_mut() += 1;
+    assert_eq!(*prefixed.get_count(), 2);
+}
+
+#[test]
+fn no_prefix_by_default() {
+    let mut plain = Plain::new(1);
+    *plain.count_mut() += 1;
+    assert_eq!(*plain.count(), 2);
+}
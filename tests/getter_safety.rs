@@ -0,0 +1,27 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_safety = "unsafe"]
+pub struct Raw {
+    addr: usize,
+    #[safe_getter]
+    len: usize,
+}
+
+#[derive(Getters)]
+#[getter_safety = "safe"]
+pub struct Plain {
+    addr: usize,
+}
+
+#[test]
+fn unsafe_getters_require_unsafe_blocks() {
+    let raw = Raw::new(8, 2);
+    assert_eq!(unsafe { *raw.addr() }, 8);
+    assert_eq!(*raw.len(), 2);
+}
+
+#[test]
+fn safe_is_accepted_explicitly() {
+    assert_eq!(*Plain::new(8).addr(), 8);
+}
@@ -0,0 +1,26 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_self_ref]
+pub struct Plain {
+    value: u8,
+}
+
+#[derive(Getters)]
+#[getter_self_ref(getter_name = "builder")]
+pub struct Named {
+    value: u8,
+}
+
+#[test]
+fn self_ref_returns_the_same_instance() {
+    let plain = Plain::new(1);
+    assert!(std::ptr::eq(plain.self_ref(), &plain));
+    assert_eq!(*plain.self_ref().value(), 1);
+}
+
+#[test]
+fn self_ref_can_be_renamed() {
+    let named = Named::new(2);
+    assert!(std::ptr::eq(named.builder(), &named));
+}
@@ -0,0 +1,12 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_suppress_warnings]
+pub struct Wrapper {
+    into_inner: u8,
+}
+
+#[test]
+fn suppressed_getters_still_work() {
+    assert_eq!(*Wrapper::new(1).into_inner(), 1);
+}
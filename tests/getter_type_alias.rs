@@ -0,0 +1,30 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Config {
+    #[getter_type_alias = "PortRef"]
+    port: u16,
+}
+
+#[derive(Getters)]
+pub struct Stack<'a, T> {
+    #[getter_type_alias = "ItemsRef"]
+    items: Vec<T>,
+    #[getter_type_alias = "NameRef"]
+    name: &'a str,
+}
+
+#[test]
+fn alias_names_the_getter_return_type() {
+    let config = Config::new(80);
+    let port: PortRef<'_> = config.port();
+    assert_eq!(*port, 80);
+}
+
+#[test]
+fn alias_carries_the_generics_used_by_the_field() {
+    let stack = Stack::new(vec![1u8], "stack");
+    let items: ItemsRef<'_, u8> = stack.items();
+    let name: NameRef<'_, '_> = stack.name();
+    assert_eq!((items.len(), *name), (1, "stack"));
+}
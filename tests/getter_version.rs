@@ -0,0 +1,28 @@
+// `missing_docs` checks that the version note is attached to the getter as documentation.
+#![deny(missing_docs)]
+//! Getters documenting the version they were introduced in.
+
+use getters::Getters;
+
+/// A configuration.
+#[derive(Getters)]
+#[skip_new]
+pub struct Config {
+    #[getter_version = "1.2.0"]
+    port: u16,
+    #[getter_version = "1.0.0"]
+    #[getter_deprecated_since = "1.3.0"]
+    legacy_port: u16,
+}
+
+#[test]
+fn versioned_getters_work() {
+    let config = Config {
+        port: 80,
+        legacy_port: 81,
+    };
+    assert_eq!(*config.port(), 80);
+    #[allow(deprecated)]
+    let legacy = *config.legacy_port();
+    assert_eq!(legacy, 81);
+}
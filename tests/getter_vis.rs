@@ -0,0 +1,39 @@
+use getters::Getters;
+
+mod inner {
+    use getters::Getters;
+
+    #[derive(Getters)]
+    #[getters(vis = "pub(crate)")]
+    pub struct Account {
+        #[vis = "pub"]
+        id: u32,
+        balance: i64,
+        #[getter_vis = ""]
+        secret: String,
+    }
+
+    impl Account {
+        pub fn secret_len(&self) -> usize {
+            self.secret().len()
+        }
+    }
+}
+
+#[derive(Getters)]
+pub struct Plain {
+    value: u8,
+}
+
+#[test]
+fn struct_and_field_visibility_apply() {
+    let account = inner::Account::new(7, -3, "hunter2".to_string());
+    assert_eq!(*account.id(), 7);
+    assert_eq!(*account.balance(), -3);
+    assert_eq!(account.secret_len(), 7);
+}
+
+#[test]
+fn getters_are_pub_by_default() {
+    assert_eq!(*Plain::new(1).value(), 1);
+}
@@ -0,0 +1,24 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_where_clause = "T: Clone + Send"]
+pub struct Wrapper<T> {
+    value: T,
+}
+
+#[test]
+fn getters_exist_when_predicates_hold() {
+    let wrapper = Wrapper::new(String::from("value"));
+    assert_eq!(wrapper.value(), "value");
+}
+
+#[test]
+fn impl_block_carries_the_predicates() {
+    fn has_value<T>(wrapper: &Wrapper<T>) -> &T
+    where
+        T: Clone + Send,
+    {
+        wrapper.value()
+    }
+    assert_eq!(*has_value(&Wrapper { value: 3 }), 3);
+}
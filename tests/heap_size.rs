@@ -0,0 +1,25 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Cache {
+    #[heap_size]
+    keys: Vec<u64>,
+    #[heap_size]
+    name: String,
+}
+
+#[test]
+fn size_is_capacity_times_element_size() {
+    let cache = Cache::new(Vec::with_capacity(10), String::with_capacity(7));
+    assert_eq!(cache.keys_heap_size(), cache.keys().capacity() * 8);
+    assert!(cache.keys_heap_size() >= 80);
+    assert_eq!(cache.name_heap_size(), cache.name().capacity());
+    assert!(cache.name_heap_size() >= 7);
+}
+
+#[test]
+fn empty_collections_hold_nothing() {
+    let cache = Cache::new(Vec::new(), String::new());
+    assert_eq!(cache.keys_heap_size(), 0);
+    assert_eq!(cache.name_heap_size(), 0);
+}
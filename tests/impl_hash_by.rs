@@ -0,0 +1,37 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use getters::Getters;
+
+#[derive(Debug, Getters)]
+#[impl_hash_by = "id"]
+#[impl_partial_eq_by = "id"]
+pub struct User {
+    id: u64,
+    name: String,
+}
+
+fn hash_of(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn only_listed_fields_are_hashed_and_compared() {
+    let first = User::new(1, "ada".to_string());
+    let renamed = User::new(1, "grace".to_string());
+    assert_eq!(first, renamed);
+    assert_eq!(hash_of(&first), hash_of(&renamed));
+    assert_ne!(first, User::new(2, "ada".to_string()));
+}
+
+#[test]
+fn usable_as_a_hash_map_key() {
+    let mut scores = HashMap::new();
+    scores.insert(User::new(1, "ada".to_string()), 10);
+    scores.insert(User::new(1, "grace".to_string()), 20);
+    assert_eq!(scores.len(), 1);
+    assert_eq!(scores[&User::new(1, String::new())], 20);
+}
@@ -0,0 +1,28 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[impl_iterator(item = "u32")]
+pub struct Only {
+    inner: std::ops::Range<u32>,
+}
+
+#[derive(Getters)]
+#[impl_iterator(item = "char", field = "chars")]
+pub struct Named {
+    label: &'static str,
+    chars: std::vec::IntoIter<char>,
+}
+
+#[test]
+fn delegates_to_the_only_field() {
+    let only = Only::new(0..3);
+    assert_eq!(only.collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[test]
+fn delegates_to_the_named_field() {
+    let mut named = Named::new("ab", vec!['a', 'b'].into_iter());
+    assert_eq!(named.next(), Some('a'));
+    assert_eq!(*named.label(), "ab");
+    assert_eq!(named.collect::<String>(), "b");
+}
@@ -0,0 +1,52 @@
+use getters::Getters;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+fn evens(values: &[u32]) -> impl Iterator<Item = u32> + '_ {
+    values.iter().copied().filter(|value| value % 2 == 0)
+}
+
+fn ready(value: &u32) -> impl Future<Output = u32> {
+    std::future::ready(*value)
+}
+
+fn adder(offset: &u32) -> impl Fn(u32) -> u32 {
+    let offset = *offset;
+    move |value| value + offset
+}
+
+#[derive(Getters)]
+pub struct Numbers {
+    #[getter_logic = "evens"]
+    #[return_type = "impl Iterator<Item = u32> + '_"]
+    values: Vec<u32>,
+    #[getter_logic = "ready"]
+    #[return_type = "impl Future<Output = u32>"]
+    pending: u32,
+    #[getter_logic = "adder"]
+    #[return_type = "impl Fn(u32) -> u32"]
+    offset: u32,
+}
+
+fn numbers() -> Numbers {
+    Numbers::new(vec![1, 2, 3, 4], 7, 10)
+}
+
+#[test]
+fn impl_iterator_return_type() {
+    assert_eq!(numbers().values().collect::<Vec<_>>(), [2, 4]);
+}
+
+#[test]
+fn impl_future_return_type() {
+    let numbers = numbers();
+    let future = pin!(numbers.pending());
+    let mut context = Context::from_waker(Waker::noop());
+    assert_eq!(future.poll(&mut context), Poll::Ready(7));
+}
+
+#[test]
+fn impl_fn_return_type() {
+    assert_eq!(numbers().offset()(5), 15);
+}
@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use getters::Getters;
+
+#[derive(Getters)]
+#[free_new = "make_server"]
+pub struct Server {
+    #[into_arg]
+    host: String,
+    #[into_arg]
+    root: PathBuf,
+    port: u16,
+}
+
+#[test]
+fn marked_arguments_accept_convertible_values() {
+    let server = Server::new("localhost", "/srv", 8080);
+    assert_eq!(server.host(), "localhost");
+    assert_eq!(server.root(), &PathBuf::from("/srv"));
+    assert_eq!(*server.port(), 8080);
+}
+
+#[test]
+fn owned_values_are_still_accepted() {
+    let server = make_server("localhost".to_string(), PathBuf::from("/srv"), 80);
+    assert_eq!(server.host(), "localhost");
+}
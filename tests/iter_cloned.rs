@@ -0,0 +1,15 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Playlist {
+    #[iter_cloned]
+    titles: Vec<String>,
+}
+
+#[test]
+fn yields_owned_clones() {
+    let playlist = Playlist::new(vec!["a".to_string(), "b".to_string()]);
+    let titles: Vec<String> = playlist.titles().collect();
+    assert_eq!(titles, ["a", "b"]);
+    assert_eq!(playlist.titles().count(), 2);
+}
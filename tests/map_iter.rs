@@ -0,0 +1,23 @@
+use getters::Getters;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Getters)]
+pub struct Registry {
+    #[map_iter]
+    ordered: BTreeMap<&'static str, u32>,
+    #[map_iter]
+    hashed: HashMap<u8, bool>,
+}
+
+#[test]
+fn keys_and_values_iterate_the_map() {
+    let registry = Registry::new(
+        BTreeMap::from([("b", 2), ("a", 1)]),
+        HashMap::from([(7, true)]),
+    );
+    assert_eq!(registry.ordered_keys().collect::<Vec<_>>(), [&"a", &"b"]);
+    assert_eq!(registry.ordered_values().sum::<u32>(), 3);
+    assert_eq!(registry.hashed_keys().copied().collect::<Vec<_>>(), [7]);
+    assert!(registry.hashed_values().all(|value| *value));
+    assert_eq!(registry.ordered().len(), 2);
+}
@@ -0,0 +1,19 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[const_assert_non_empty]
+pub struct Single(u8);
+
+#[derive(Getters)]
+#[min_fields = 2]
+pub struct Pair {
+    left: u8,
+    right: u8,
+}
+
+#[test]
+fn structs_meeting_the_minimum_compile() {
+    assert_eq!(*Single::new(1).get_0(), 1);
+    let pair = Pair::new(1, 2);
+    assert_eq!((*pair.left(), *pair.right()), (1, 2));
+}
@@ -0,0 +1,27 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Cursor<'a> {
+    #[get_mut]
+    buffer: &'a mut Vec<u8>,
+}
+
+#[test]
+fn shared_getter_returns_the_referent() {
+    let mut data = vec![1, 2];
+    let cursor = Cursor::new(&mut data);
+    let buffer: &Vec<u8> = cursor.buffer();
+    assert_eq!(buffer, &[1, 2]);
+}
+
+#[test]
+fn mut_getter_reborrows_the_referent() {
+    let mut data = vec![1];
+    {
+        let mut cursor = Cursor::new(&mut data);
+        cursor.buffer_mut().push(2);
+        cursor.buffer_mut().push(3);
+        assert_eq!(cursor.buffer().len(), 3);
+    }
+    assert_eq!(data, [1, 2, 3]);
+}
@@ -0,0 +1,19 @@
+use getters::Getters;
+use std::collections::HashMap;
+
+#[derive(Getters)]
+pub struct Registry {
+    name: String,
+    #[new_default = "HashMap::new"]
+    entries: HashMap<String, u32>,
+    #[new_default = "|| 8080"]
+    port: u16,
+}
+
+#[test]
+fn defaulted_fields_are_left_out_of_new() {
+    let registry = Registry::new("main".to_string());
+    assert_eq!(registry.name(), "main");
+    assert!(registry.entries().is_empty());
+    assert_eq!(*registry.port(), 8080);
+}
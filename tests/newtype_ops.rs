@@ -0,0 +1,25 @@
+use getters::Getters;
+
+#[derive(Debug, PartialEq, Getters)]
+#[newtype_ops]
+pub struct Meters(f64);
+
+#[derive(Debug, PartialEq, Getters)]
+#[newtype_ops]
+pub struct Count<T> {
+    value: T,
+}
+
+#[test]
+fn operators_apply_to_the_inner_value() {
+    assert_eq!(Meters(1.5) + Meters(2.0), Meters(3.5));
+    assert_eq!(Meters(5.0) - Meters(2.0), Meters(3.0));
+    assert_eq!(Meters(2.0) * Meters(4.0), Meters(8.0));
+    assert_eq!(Meters(9.0) / Meters(3.0), Meters(3.0));
+}
+
+#[test]
+fn generic_newtypes_get_operators() {
+    let total = Count::new(3u32) + Count::new(4);
+    assert_eq!(*total.value(), 7);
+}
@@ -0,0 +1,37 @@
+use std::cmp::Ordering;
+
+use getters::Getters;
+
+#[derive(Debug, Getters)]
+#[ord_by(priority, id)]
+#[impl_partial_eq_by = "priority, id"]
+pub struct Task {
+    priority: u8,
+    id: u32,
+    title: String,
+}
+
+#[test]
+fn listed_fields_are_compared_in_order() {
+    let urgent = Task::new(1, 9, "urgent".to_string());
+    let later = Task::new(2, 1, "later".to_string());
+    let also_urgent = Task::new(1, 10, "also urgent".to_string());
+    assert_eq!(urgent.cmp(&later), Ordering::Less);
+    assert_eq!(urgent.cmp(&also_urgent), Ordering::Less);
+    assert_eq!(
+        urgent.partial_cmp(&Task::new(1, 9, String::new())),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn sorting_uses_the_ordering() {
+    let mut tasks = [
+        Task::new(2, 1, "c".to_string()),
+        Task::new(1, 2, "b".to_string()),
+        Task::new(1, 1, "a".to_string()),
+    ];
+    tasks.sort();
+    let titles: Vec<_> = tasks.iter().map(|task| task.title().as_str()).collect();
+    assert_eq!(titles, ["a", "b", "c"]);
+}
@@ -0,0 +1,27 @@
+use getters::Getters;
+use std::cell::OnceCell;
+use std::net::SocketAddr;
+
+#[derive(Getters)]
+pub struct Endpoint {
+    #[parsed_cache(into = "addr", as = "SocketAddr")]
+    raw: String,
+    #[skip_getter]
+    addr: OnceCell<SocketAddr>,
+}
+
+#[test]
+fn parses_once_and_caches_the_value() {
+    let endpoint = Endpoint::new("127.0.0.1:80".to_string(), OnceCell::new());
+    assert!(endpoint.addr.get().is_none());
+    assert_eq!(endpoint.addr().port(), 80);
+    assert!(std::ptr::eq(endpoint.addr(), endpoint.addr.get().unwrap()));
+    assert_eq!(endpoint.raw(), "127.0.0.1:80");
+}
+
+#[test]
+#[should_panic(expected = "failed to parse `raw`")]
+fn invalid_input_panics_on_access() {
+    let endpoint = Endpoint::new("not an address".to_string(), OnceCell::new());
+    let _ = endpoint.addr();
+}
@@ -0,0 +1,30 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getters(prefix = "get_", suffix = "_value")]
+pub struct Settings {
+    #[get_mut]
+    name: String,
+    #[getter_name = "level"]
+    verbosity: u8,
+}
+
+#[derive(Getters)]
+#[getters(suffix = "_ref")]
+pub struct Suffixed {
+    name: String,
+}
+
+#[test]
+fn affixes_apply_to_every_getter() {
+    let mut settings = Settings::new("app".to_string(), 2);
+    settings.get_name_value_mut().push_str("-dev");
+    assert_eq!(settings.get_name_value(), "app-dev");
+    assert_eq!(*settings.get_level_value(), 2);
+}
+
+#[test]
+fn each_affix_defaults_to_empty() {
+    let suffixed = Suffixed::new("only".to_string());
+    assert_eq!(suffixed.name_ref(), "only");
+}
@@ -0,0 +1,29 @@
+use getters::Getters;
+
+pub struct Item {
+    id: u64,
+}
+
+fn get_id(item: &Item) -> u64 {
+    item.id
+}
+
+#[derive(Getters)]
+pub struct Order {
+    #[project_vec = "get_id"]
+    #[return_type = "Vec<u64>"]
+    items: Vec<Item>,
+}
+
+#[test]
+fn elements_are_projected_in_order() {
+    let order = Order::new(vec![Item { id: 3 }, Item { id: 1 }]);
+    assert_eq!(order.items_ids(), vec![3, 1]);
+}
+
+#[test]
+fn plain_getter_keeps_its_type() {
+    let order = Order::new(vec![Item { id: 3 }]);
+    let items: &Vec<Item> = order.items();
+    assert_eq!(items.len(), 1);
+}
@@ -0,0 +1,30 @@
+use getters::Getters;
+
+#[derive(Debug, Getters)]
+pub struct Volume {
+    #[range = "0..=100"]
+    level: u8,
+    #[range = "1.."]
+    channels: u32,
+}
+
+#[test]
+fn values_in_range_construct() {
+    let volume = Volume::new(100, 2);
+    assert_eq!(*volume.level(), 100);
+    assert!(Volume::try_new(0, 1).is_ok());
+}
+
+#[test]
+fn try_new_names_the_field_out_of_range() {
+    let error = Volume::try_new(101, 2).unwrap_err();
+    assert_eq!(error, "field `level` is out of range `0..=100`");
+    let error = Volume::try_new(50, 0).unwrap_err();
+    assert_eq!(error, "field `channels` is out of range `1..`");
+}
+
+#[test]
+#[should_panic(expected = "field `level` is out of range `0..=100`")]
+fn new_panics_out_of_range() {
+    Volume::new(200, 2);
+}
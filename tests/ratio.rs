@@ -0,0 +1,29 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[ratio(name = "completion", numerator = "done", denominator = "total")]
+#[ratio(
+    name = "failure_rate",
+    numerator = "failed",
+    denominator = "done",
+    on_zero = "nan"
+)]
+pub struct Progress {
+    done: u32,
+    failed: u16,
+    total: u64,
+}
+
+#[test]
+fn fields_are_divided_as_f64() {
+    let progress = Progress::new(2, 1, 8);
+    assert_eq!(progress.completion(), 0.25);
+    assert_eq!(progress.failure_rate(), 0.5);
+}
+
+#[test]
+fn zero_denominator_returns_zero_or_nan() {
+    let progress = Progress::new(0, 0, 0);
+    assert_eq!(progress.completion(), 0.0);
+    assert!(progress.failure_rate().is_nan());
+}
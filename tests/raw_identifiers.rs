@@ -0,0 +1,261 @@
+use getters::Getters;
+
+fn scaled(value: &u32, factor: u32) -> u32 {
+    value * factor
+}
+
+#[derive(Getters)]
+pub struct Memoized {
+    #[getter_logic = "scaled"]
+    #[getter_memoize(key_type = "u32")]
+    r#mod: u32,
+    #[skip_getter]
+    mod_cache: std::cell::RefCell<std::collections::HashMap<u32, u32>>,
+}
+
+#[test]
+fn memoize_cache_drops_the_prefix() {
+    let memoized = Memoized::new(5, Default::default());
+    assert_eq!(memoized.r#mod(3), 15);
+    assert_eq!(memoized.mod_cache.borrow().get(&3), Some(&15));
+}
+
+#[derive(Getters)]
+pub struct Mutable {
+    #[get_mut]
+    r#type: u8,
+}
+
+#[test]
+fn mutable_getter_drops_the_prefix() {
+    let mut mutable = Mutable::new(1);
+    *mutable.type_mut() += 1;
+    assert_eq!(*mutable.r#type(), 2);
+}
+
+#[derive(Getters)]
+pub struct Mapped {
+    #[map_iter]
+    r#match: std::collections::HashMap<u8, u8>,
+}
+
+#[test]
+fn map_iter_drops_the_prefix() {
+    let mapped = Mapped::new([(1, 2)].into());
+    assert_eq!(mapped.match_keys().collect::<Vec<_>>(), [&1]);
+    assert_eq!(mapped.match_values().collect::<Vec<_>>(), [&2]);
+}
+
+#[derive(Getters)]
+pub struct Settable {
+    #[set]
+    r#type: u8,
+}
+
+#[test]
+fn setter_drops_the_prefix() {
+    let mut settable = Settable::new(1);
+    settable.set_type(2);
+    assert_eq!(*settable.r#type(), 2);
+}
+
+#[derive(Getters)]
+pub struct Updatable {
+    #[update]
+    r#type: Vec<u32>,
+}
+
+#[test]
+fn update_drops_the_prefix() {
+    let mut updatable = Updatable::new(vec![1]);
+    updatable.update_type(|mut values| {
+        values.push(2);
+        values
+    });
+    assert_eq!(updatable.r#type(), &[1, 2]);
+}
+
+#[derive(Getters)]
+pub struct Fillable {
+    #[set_if_none]
+    r#fn: Option<u8>,
+}
+
+#[test]
+fn set_if_none_drops_the_prefix() {
+    let mut fillable = Fillable::new(None);
+    assert_eq!(*fillable.fn_set_if_none(6), 6);
+}
+
+#[derive(Getters)]
+pub struct Locked {
+    #[read_getter]
+    r#ref: std::sync::RwLock<u8>,
+}
+
+#[test]
+fn read_getter_guards_drop_the_prefix() {
+    let locked = Locked::new(std::sync::RwLock::new(4));
+    *locked.ref_write() += 1;
+    assert_eq!(*locked.ref_try_read().unwrap(), 5);
+    assert!(locked.ref_try_write().is_some());
+}
+
+#[derive(Getters)]
+pub struct Sortable {
+    #[sorted_view]
+    r#type: Vec<u32>,
+}
+
+#[test]
+fn sorted_view_drops_the_prefix() {
+    assert_eq!(Sortable::new(vec![3, 1, 2]).type_sorted(), [1, 2, 3]);
+}
+
+#[derive(Getters)]
+pub struct Hashed {
+    #[stable_hash]
+    r#in: u64,
+}
+
+#[test]
+fn stable_hash_drops_the_prefix() {
+    assert_eq!(Hashed::new(8).in_hash(), Hashed::new(8).in_hash());
+}
+
+#[derive(Getters)]
+pub struct Shared {
+    #[shared_clone]
+    r#use: std::sync::Arc<u8>,
+}
+
+#[test]
+fn shared_clone_drops_the_prefix() {
+    assert_eq!(*Shared::new(std::sync::Arc::new(7)).use_shared(), 7);
+}
+
+pub struct Item {
+    id: u64,
+}
+
+fn get_id(item: &Item) -> u64 {
+    item.id
+}
+
+#[derive(Getters)]
+pub struct Projected {
+    #[project_vec = "get_id"]
+    #[return_type = "Vec<u64>"]
+    r#struct: Vec<Item>,
+}
+
+#[test]
+fn project_vec_drops_the_prefix() {
+    assert_eq!(Projected::new(vec![Item { id: 11 }]).struct_ids(), [11]);
+}
+
+#[derive(Getters)]
+pub struct Deduplicated {
+    #[unique]
+    r#type: Vec<u32>,
+}
+
+#[test]
+fn unique_drops_the_prefix() {
+    assert_eq!(Deduplicated::new(vec![3, 1, 3]).type_unique(), [3, 1]);
+}
+
+#[derive(Getters)]
+pub struct Sequence {
+    #[first]
+    #[last]
+    r#type: Vec<u32>,
+}
+
+#[test]
+fn first_and_last_drop_the_prefix() {
+    let sequence = Sequence::new(vec![3, 1]);
+    assert_eq!(
+        (sequence.type_first(), sequence.type_last()),
+        (Some(&3), Some(&1))
+    );
+}
+
+#[derive(Getters)]
+pub struct Measured {
+    #[heap_size]
+    r#type: Vec<u32>,
+}
+
+#[test]
+fn heap_size_drops_the_prefix() {
+    assert!(Measured::new(vec![1, 2, 3]).type_heap_size() >= 3 * std::mem::size_of::<u32>());
+}
+
+#[derive(Getters)]
+pub struct Stamped {
+    #[rfc3339]
+    r#loop: std::time::SystemTime,
+}
+
+#[test]
+fn rfc3339_drops_the_prefix() {
+    let stamped = Stamped::new(std::time::UNIX_EPOCH);
+    assert_eq!(stamped.loop_rfc3339(), "1970-01-01T00:00:00Z");
+}
+
+#[derive(Getters)]
+pub struct Formatted {
+    #[format = "{:.1}"]
+    r#where: f64,
+}
+
+#[test]
+fn format_drops_the_prefix() {
+    assert_eq!(Formatted::new(1.25).where_fmt(), "1.2");
+}
+
+#[derive(Getters)]
+pub struct Timed {
+    #[duration_parts]
+    r#loop: std::time::Duration,
+}
+
+#[test]
+fn duration_parts_drop_the_prefix() {
+    let timed = Timed::new(std::time::Duration::from_millis(1500));
+    assert_eq!((timed.loop_secs(), timed.loop_millis()), (1, 1500));
+}
+
+#[derive(Getters)]
+#[builder]
+pub struct Built {
+    r#type: u8,
+}
+
+#[test]
+fn builder_setters_drop_the_prefix() {
+    let built = Built::builder().with_type(2).build().unwrap();
+    assert_eq!(*built.r#type(), 2);
+}
+
+#[cfg(feature = "semver")]
+#[derive(Getters)]
+pub struct Versioned {
+    #[semver]
+    r#use: String,
+}
+
+#[cfg(feature = "semver")]
+#[test]
+fn semver_components_drop_the_prefix() {
+    let versioned = Versioned::new("1.22.3".to_string());
+    assert_eq!(
+        (
+            versioned.use_major(),
+            versioned.use_minor(),
+            versioned.use_patch()
+        ),
+        (1, 22, 3)
+    );
+}
@@ -0,0 +1,24 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Buffer {
+    #[raw_parts]
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn pointer_and_length_match_the_vec() {
+    let buffer = Buffer::new(vec![1, 2, 3]);
+    assert_eq!(buffer.as_ptr(), buffer.bytes().as_ptr());
+    assert_eq!(buffer.len(), 3);
+    assert!(!buffer.is_empty());
+    let copied = unsafe { std::slice::from_raw_parts(buffer.as_ptr(), buffer.len()) };
+    assert_eq!(copied, [1, 2, 3]);
+}
+
+#[test]
+fn empty_buffer() {
+    let buffer = Buffer::new(Vec::new());
+    assert_eq!(buffer.len(), 0);
+    assert!(buffer.is_empty());
+}
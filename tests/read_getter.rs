@@ -0,0 +1,40 @@
+use getters::Getters;
+use std::sync::{Arc, RwLock};
+
+#[derive(Getters)]
+pub struct State {
+    #[read_getter]
+    local: RwLock<Vec<u8>>,
+    #[read_getter]
+    shared: Arc<RwLock<String>>,
+}
+
+fn state() -> State {
+    State::new(RwLock::new(vec![1]), Arc::new(RwLock::new("a".to_string())))
+}
+
+#[test]
+fn read_returns_a_guard() {
+    let state = state();
+    assert_eq!(*state.local(), [1]);
+    assert_eq!(state.shared().as_str(), "a");
+}
+
+#[test]
+fn write_guards_mutate_the_value() {
+    let state = state();
+    state.local_write().push(2);
+    state.shared_write().push('b');
+    assert_eq!(*state.local(), [1, 2]);
+    assert_eq!(*state.shared(), "ab");
+}
+
+#[test]
+fn try_guards_fail_while_locked() {
+    let state = state();
+    let reader = state.local();
+    assert!(state.local_try_read().is_some());
+    assert!(state.local_try_write().is_none());
+    drop(reader);
+    assert!(state.local_try_write().is_some());
+}
@@ -0,0 +1,67 @@
+#![deny(deprecated)]
+
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+use getters::Getters;
+
+#[derive(Getters)]
+#[readonly_proxy]
+pub struct Account {
+    #[get_mut]
+    #[set]
+    owner: String,
+    #[getter_fallible]
+    balance: i64,
+    #[expect_some = "account has no email"]
+    email: Option<String>,
+    #[getter_deprecated_since = "2.0.0"]
+    legacy_id: u32,
+}
+
+#[derive(Getters)]
+#[readonly_proxy]
+pub struct Pair(u8, String);
+
+fn describe(account: AccountReadOnly<'_>) -> String {
+    format!("{}: {}", account.owner(), account.balance())
+}
+
+#[test]
+fn proxy_exposes_the_getters() {
+    let mut account = Account::new("ada".to_string(), 10, None, 1);
+    account.set_owner("grace".to_string());
+    assert_eq!(describe(account.as_readonly()), "grace: 10");
+    assert_eq!(account.as_readonly().try_balance(), Some(&10));
+}
+
+#[test]
+#[allow(deprecated)]
+fn proxy_forwards_deprecated_getters() {
+    let account = Account::new("ada".to_string(), 10, None, 7);
+    assert_eq!(*account.as_readonly().legacy_id(), 7);
+}
+
+#[test]
+fn proxy_keeps_the_caller_location() {
+    let location = Arc::new(Mutex::new(None));
+    let recorded = Arc::clone(&location);
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        *recorded.lock().unwrap() = info.location().map(|location| location.line());
+    }));
+    let account = Account::new("ada".to_string(), 10, None, 1);
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| account.as_readonly().email().len());
+    panic::set_hook(previous);
+
+    assert!(result.is_err());
+    assert_eq!(location.lock().unwrap().take(), Some(line));
+}
+
+#[test]
+fn proxy_mirrors_tuple_getters() {
+    let pair = Pair::new(1, "one".to_string());
+    let proxy: PairReadOnly<'_> = pair.as_readonly();
+    assert_eq!((proxy.get_0(), proxy.get_1().as_str()), (&1, "one"));
+}
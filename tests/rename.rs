@@ -0,0 +1,31 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Token {
+    #[rename = "kind"]
+    #[get_mut]
+    type_: u8,
+    #[rename = "is_keyword"]
+    #[copy]
+    keyword: bool,
+}
+
+#[derive(Getters)]
+#[getters(prefix = "get_")]
+pub struct Prefixed {
+    #[rename = "kind"]
+    type_: u8,
+}
+
+#[test]
+fn getters_use_the_new_name() {
+    let mut token = Token::new(1, true);
+    *token.kind_mut() = 2;
+    assert_eq!(*token.kind(), 2);
+    assert!(token.is_keyword());
+}
+
+#[test]
+fn prefix_applies_to_the_new_name() {
+    assert_eq!(*Prefixed::new(3).get_kind(), 3);
+}
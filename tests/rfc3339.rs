@@ -0,0 +1,37 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Record {
+    #[rfc3339]
+    created: SystemTime,
+}
+
+#[test]
+fn system_time_is_formatted_in_utc() {
+    let record = Record::new(UNIX_EPOCH);
+    assert_eq!(record.created_rfc3339(), "1970-01-01T00:00:00Z");
+    // 2024-02-29T13:45:30.75Z, a leap day; the fraction is dropped.
+    let record = Record::new(UNIX_EPOCH + Duration::from_millis(1_709_214_330_750));
+    assert_eq!(record.created_rfc3339(), "2024-02-29T13:45:30Z");
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_fields {
+    use chrono::{DateTime, TimeZone, Utc};
+    use getters::Getters;
+
+    #[derive(Getters)]
+    pub struct Record {
+        #[rfc3339]
+        created: DateTime<Utc>,
+    }
+
+    #[test]
+    fn date_time_uses_chrono_formatting() {
+        let created = Utc.with_ymd_and_hms(2024, 2, 29, 13, 45, 30).unwrap();
+        let record = Record::new(created);
+        assert_eq!(record.created_rfc3339(), created.to_rfc3339());
+    }
+}
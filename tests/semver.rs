@@ -0,0 +1,26 @@
+#![cfg(feature = "semver")]
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Package {
+    #[semver]
+    version: String,
+    #[semver]
+    minimum: &'static str,
+}
+
+#[test]
+fn components_are_parsed() {
+    let package = Package::new("1.22.3-beta.1".to_string(), "0.4.0");
+    assert_eq!(package.version_major(), 1);
+    assert_eq!(package.version_minor(), 22);
+    assert_eq!(package.version_patch(), 3);
+    assert_eq!(package.minimum_minor(), 4);
+}
+
+#[test]
+#[should_panic(expected = "`version` is not a valid semantic version")]
+fn invalid_version_panics() {
+    Package::new("1.2".to_string(), "0.4.0").version_major();
+}
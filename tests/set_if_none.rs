@@ -0,0 +1,20 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Session {
+    #[set_if_none]
+    token: Option<String>,
+}
+
+#[test]
+fn fills_an_empty_option() {
+    let mut session = Session::new(None);
+    assert_eq!(session.token_set_if_none("first".to_string()), "first");
+    assert_eq!(session.token().as_deref(), Some("first"));
+}
+
+#[test]
+fn keeps_an_existing_value() {
+    let mut session = Session::new(Some("kept".to_string()));
+    assert_eq!(session.token_set_if_none("ignored".to_string()), "kept");
+}
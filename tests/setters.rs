@@ -0,0 +1,31 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Single {
+    #[set]
+    port: u16,
+    host: String,
+}
+
+#[derive(Getters)]
+#[getters(set_all)]
+pub struct All {
+    port: u16,
+    #[skip_set]
+    host: String,
+}
+
+#[test]
+fn set_replaces_the_field() {
+    let mut single = Single::new(80, "localhost".to_string());
+    single.set_port(8080);
+    assert_eq!(*single.port(), 8080);
+    assert_eq!(single.host(), "localhost");
+}
+
+#[test]
+fn set_all_generates_every_setter_but_skipped_ones() {
+    let mut all = All::new(80, "localhost".to_string());
+    all.set_port(443);
+    assert_eq!(*all.port(), 443);
+}
@@ -0,0 +1,32 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Cache {
+    #[shared_clone]
+    entries: Arc<Vec<u32>>,
+    #[shared_clone]
+    name: Rc<str>,
+    // The `tracing` feature of this crate stands in for a feature of the user's crate.
+    #[shared_clone(feature = "tracing")]
+    gated: Arc<u8>,
+}
+
+#[test]
+fn shared_clones_point_to_the_same_value() {
+    let cache = Cache::new(Arc::new(vec![1, 2]), Rc::from("cache"), Arc::new(0));
+    let entries = cache.entries_shared();
+    assert!(Arc::ptr_eq(&entries, cache.entries()));
+    assert_eq!(Arc::strong_count(&entries), 2);
+    assert_eq!(&*cache.name_shared(), "cache");
+    assert_eq!(Rc::strong_count(cache.name()), 1);
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn feature_gated_shared_clone_is_emitted_with_the_feature() {
+    let cache = Cache::new(Arc::new(vec![]), Rc::from(""), Arc::new(7));
+    assert_eq!(*cache.gated_shared(), 7);
+}
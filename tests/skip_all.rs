@@ -0,0 +1,22 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[skip_all]
+pub struct Session {
+    #[include_getter]
+    user: String,
+    token: String,
+}
+
+impl Session {
+    fn token(&self) -> String {
+        "*".repeat(self.token.len())
+    }
+}
+
+#[test]
+fn only_included_fields_get_getters() {
+    let session = Session::new("ada".to_string(), "secret".to_string());
+    assert_eq!(session.user(), "ada");
+    assert_eq!(session.token(), "******");
+}
@@ -0,0 +1,14 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Scores {
+    #[sorted_view]
+    values: Vec<u32>,
+}
+
+#[test]
+fn returns_a_sorted_copy() {
+    let scores = Scores::new(vec![3, 1, 2]);
+    assert_eq!(scores.values_sorted(), [1, 2, 3]);
+    assert_eq!(scores.values(), &[3, 1, 2]);
+}
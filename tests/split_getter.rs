@@ -0,0 +1,22 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[split_getter(buffer = "data", at = "consumed")]
+pub struct Reader {
+    data: Vec<u8>,
+    consumed: usize,
+}
+
+#[test]
+fn buffer_is_split_at_the_index() {
+    let reader = Reader::new(vec![1, 2, 3, 4], 1);
+    assert_eq!(reader.head(), &[1]);
+    assert_eq!(reader.tail(), &[2, 3, 4]);
+}
+
+#[test]
+fn index_is_clamped_to_the_buffer_length() {
+    let reader = Reader::new(vec![1, 2], 10);
+    assert_eq!(reader.head(), &[1, 2]);
+    assert!(reader.tail().is_empty());
+}
@@ -0,0 +1,44 @@
+use getters::Getters;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[derive(Default)]
+pub struct Sum(u64);
+
+impl Hasher for Sum {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 += bytes.iter().map(|byte| u64::from(*byte)).sum::<u64>();
+    }
+}
+
+#[derive(Getters)]
+pub struct Document {
+    #[stable_hash]
+    body: String,
+    #[stable_hash = "Sum"]
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn default_hasher_matches_hashing_the_field() {
+    let document = Document::new("body".to_string(), Vec::new());
+    let mut hasher = DefaultHasher::new();
+    "body".to_string().hash(&mut hasher);
+    assert_eq!(document.body_hash(), hasher.finish());
+    assert_eq!(
+        document.body_hash(),
+        Document::new("body".to_string(), vec![1]).body_hash()
+    );
+}
+
+#[test]
+fn custom_hasher_is_used() {
+    let document = Document::new(String::new(), vec![1, 2, 3]);
+    let mut hasher = Sum::default();
+    vec![1u8, 2, 3].hash(&mut hasher);
+    assert_eq!(document.bytes_hash(), hasher.finish());
+}
@@ -0,0 +1,23 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[struct_of_arrays_view]
+pub struct Particles {
+    positions: Vec<(f32, f32)>,
+    masses: Vec<f32>,
+}
+
+#[test]
+fn row_borrows_one_element_of_each_field() {
+    let particles = Particles::new(vec![(0.0, 1.0), (2.0, 3.0)], vec![1.5, 2.5]);
+    let row: ParticlesRow<'_> = particles.row(1).unwrap();
+    assert_eq!(row.positions, &(2.0, 3.0));
+    assert_eq!(row.masses, &2.5);
+}
+
+#[test]
+fn row_is_none_past_the_shortest_field() {
+    let particles = Particles::new(vec![(0.0, 1.0), (2.0, 3.0)], vec![1.5]);
+    assert!(particles.row(0).is_some());
+    assert!(particles.row(1).is_none());
+}
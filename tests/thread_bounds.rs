@@ -0,0 +1,42 @@
+use getters::Getters;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Getters)]
+#[getter_send_bound]
+pub struct SendOnly {
+    shared: Arc<u8>,
+    #[no_send_bound]
+    local: Rc<u8>,
+}
+
+#[test]
+fn send_fields_and_opted_out_fields_compile() {
+    let value = SendOnly::new(Arc::new(1), Rc::new(2));
+    assert_eq!((**value.shared(), **value.local()), (1, 2));
+}
+
+#[derive(Getters)]
+#[getter_send_bound]
+#[getter_sync_bound]
+pub struct SendSync {
+    counter: Arc<u8>,
+}
+
+#[derive(Getters)]
+#[getter_sync_bound]
+pub struct SyncOnly {
+    guard: std::sync::MutexGuard<'static, u8>,
+}
+
+#[test]
+fn send_and_sync_bounds_combine() {
+    assert_eq!(**SendSync::new(Arc::new(3)).counter(), 3);
+}
+
+#[test]
+fn sync_bound_alone_accepts_non_send_fields() {
+    static LOCK: std::sync::Mutex<u8> = std::sync::Mutex::new(4);
+    let sync_only = SyncOnly::new(LOCK.lock().unwrap());
+    assert_eq!(**sync_only.guard(), 4);
+}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Event {
+    #[auto_now]
+    created_at: u64,
+}
+
+fn main() {}
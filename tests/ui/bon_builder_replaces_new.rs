@@ -0,0 +1,11 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[derive(bon::Builder)]
+pub struct WithBon {
+    port: u16,
+}
+
+fn main() {
+    let _ = WithBon::new(80);
+}
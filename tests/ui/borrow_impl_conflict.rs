@@ -0,0 +1,11 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Names {
+    #[borrow_impl = "str"]
+    first: String,
+    #[borrow_impl = "str"]
+    last: String,
+}
+
+fn main() {}
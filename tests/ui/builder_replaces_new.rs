@@ -0,0 +1,11 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[builder]
+pub struct BuilderOnly {
+    port: u16,
+}
+
+fn main() {
+    let _ = BuilderOnly::new(80);
+}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[checksum(over = "body", algo = "md5")]
+pub struct Packet {
+    body: Vec<u8>,
+}
+
+fn main() {}
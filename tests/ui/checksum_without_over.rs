@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[checksum(algo = "crc32")]
+pub struct Packet {
+    body: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Pixel {
+    #[clamp = "0..256"]
+    red: i32,
+}
+
+fn main() {}
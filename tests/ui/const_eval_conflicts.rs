@@ -0,0 +1,29 @@
+use getters::Getters;
+
+fn record(_owner: &str, _field: &str) {}
+
+#[derive(Getters)]
+#[field_access_hook = "record"]
+pub struct Hooked {
+    #[copy]
+    #[getter_const_eval]
+    port: u16,
+}
+
+#[derive(Getters)]
+pub struct Exclusive {
+    #[copy]
+    #[getter_const_eval]
+    #[getter_exclusive]
+    port: u16,
+}
+
+#[derive(Getters)]
+pub struct Checked {
+    #[copy]
+    #[getter_const_eval]
+    #[debug_checked = "|port: &u16| *port != 0"]
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use getters::Getters;
+
+fn has_tabs(text: &str) -> bool {
+    text.contains('\t')
+}
+
+#[derive(Getters)]
+pub struct Source {
+    #[cow_if(predicate = "has_tabs")]
+    code: String,
+    #[cow_if(predicate = "has_tabs", own = "str::to_uppercase")]
+    text: String,
+}
+
+fn main() {}
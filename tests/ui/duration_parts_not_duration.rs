@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Timeout {
+    #[duration_parts]
+    limit_ms: u64,
+}
+
+fn main() {}
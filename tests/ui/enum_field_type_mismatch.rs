@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub enum Shape {
+    Circle { id: u32, radius: f64 },
+    Square { id: u64, side: f64 },
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use getters::Getters;
+use std::rc::Rc;
+
+#[derive(Getters)]
+#[getter_send_bound]
+pub enum Shared {
+    A { value: Rc<u8> },
+}
+
+fn main() {
+    let _ = Shared::A { value: Rc::new(1) }.value();
+}
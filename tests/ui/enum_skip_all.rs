@@ -0,0 +1,11 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[skip_all]
+pub enum Partial {
+    A { hidden: u8 },
+}
+
+fn main() {
+    let _ = Partial::A { hidden: 1 }.hidden();
+}
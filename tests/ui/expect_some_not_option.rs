@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Connection {
+    #[expect_some = "connection has no peer"]
+    peer: String,
+}
+
+fn main() {}
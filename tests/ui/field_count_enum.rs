@@ -0,0 +1,10 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[field_count]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Permissions {
+    #[flag(name = "readable")]
+    read_bits: u8,
+    #[flag(bit = 1)]
+    write_bits: u8,
+    #[flag(name = "executable", bit = 2, mask = 4)]
+    exec_bits: u8,
+}
+
+fn main() {}
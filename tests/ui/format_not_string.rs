@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Reading {
+    #[format = 2]
+    celsius: f64,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[skip_new]
+pub struct Config {
+    #[deprecated(note = "use `address` instead")]
+    #[forward_attrs(deprecated)]
+    port: u16,
+}
+
+fn main() {
+    #[allow(deprecated)]
+    let config = Config { port: 80 };
+    #[deny(deprecated)]
+    let _ = config.port();
+}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Samples {
+    #[get_slice]
+    label: String,
+}
+
+fn main() {}
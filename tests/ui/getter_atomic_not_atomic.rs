@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Stats {
+    #[getter_atomic]
+    hits: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Config {
+    #[getter_deprecated_since = "1.3.0"]
+    legacy_port: u16,
+}
+
+fn main() {
+    let _ = Config::new(80).legacy_port();
+}
@@ -0,0 +1,13 @@
+use getters::Getters;
+
+fn length(text: String) -> String {
+    text.len().to_string()
+}
+
+#[derive(Getters)]
+pub struct Message {
+    #[getter_logic_copy = "length"]
+    body: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use getters::Getters;
+
+fn parse_port(text: &str) -> Result<u16, std::num::ParseIntError> {
+    text.parse()
+}
+
+#[derive(Getters)]
+pub struct Listener {
+    #[getter_map_result = "parse_port"]
+    port: String,
+}
+
+fn main() {}
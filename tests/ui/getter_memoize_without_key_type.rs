@@ -0,0 +1,36 @@
+use getters::Getters;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+fn square(value: &u32) -> u32 {
+    value * value
+}
+
+#[derive(Getters)]
+pub struct Bare {
+    #[getter_logic = "square"]
+    #[getter_memoize]
+    value: u32,
+    #[skip_getter]
+    value_cache: RefCell<HashMap<u32, u32>>,
+}
+
+#[derive(Getters)]
+pub struct Empty {
+    #[getter_logic = "square"]
+    #[getter_memoize()]
+    value: u32,
+    #[skip_getter]
+    value_cache: RefCell<HashMap<u32, u32>>,
+}
+
+#[derive(Getters)]
+pub struct Misspelled {
+    #[getter_logic = "square"]
+    #[getter_memoize(key = "u32")]
+    value: u32,
+    #[skip_getter]
+    value_cache: RefCell<HashMap<u32, u32>>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Token {
+    #[getter_name = "kind of"]
+    type_: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use getters::Getters;
+
+fn trimmed(text: &str) -> &str {
+    text.trim()
+}
+
+#[derive(Getters)]
+pub struct Document {
+    #[getter_pipe = "trimmed"]
+    body: String,
+}
+
+fn main() {}
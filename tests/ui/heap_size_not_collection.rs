@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Cache {
+    #[heap_size]
+    keys: Box<[u64]>,
+}
+
+fn main() {}
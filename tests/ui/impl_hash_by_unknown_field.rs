@@ -0,0 +1,10 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[impl_hash_by = "id, email"]
+pub struct User {
+    id: u64,
+    name: String,
+}
+
+fn main() {}
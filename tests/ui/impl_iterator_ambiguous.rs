@@ -0,0 +1,10 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[impl_iterator(item = "u32")]
+pub struct Two {
+    first: std::ops::Range<u32>,
+    second: std::ops::Range<u32>,
+}
+
+fn main() {}
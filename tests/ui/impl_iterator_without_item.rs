@@ -0,0 +1,21 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[impl_iterator]
+pub struct Bare {
+    values: std::ops::Range<u32>,
+}
+
+#[derive(Getters)]
+#[impl_iterator(field = "values")]
+pub struct FieldOnly {
+    values: std::ops::Range<u32>,
+}
+
+#[derive(Getters)]
+#[impl_iterator(items = "u32")]
+pub struct Misspelled {
+    values: std::ops::Range<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+mod inner {
+    use getters::Getters;
+
+    #[derive(Getters)]
+    #[getter_visibility_rule = "inherit_field"]
+    pub struct Fields {
+        hidden: u8,
+    }
+}
+
+fn main() {
+    let fields = inner::Fields::new(1);
+    let _ = fields.hidden();
+}
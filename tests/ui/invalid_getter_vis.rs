@@ -0,0 +1,21 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Field {
+    #[getter_vis = "pub(crat)"]
+    port: u16,
+}
+
+#[derive(Getters)]
+#[getter_vis = "public"]
+pub struct Struct {
+    port: u16,
+}
+
+#[derive(Getters)]
+#[getters(vis = "pub(crat)")]
+pub struct Nested {
+    port: u16,
+}
+
+fn main() {}
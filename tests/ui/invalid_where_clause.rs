@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_where_clause = "T: Clone; U"]
+pub struct Wrapper<T> {
+    value: T,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Route {
+    #[last]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Registry {
+    #[map_iter]
+    entries: Vec<(u8, u8)>,
+}
+
+fn main() {}
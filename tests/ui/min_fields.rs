@@ -0,0 +1,15 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[const_assert_non_empty]
+pub struct Empty {}
+
+#[derive(Getters)]
+#[const_assert_non_empty]
+pub struct EmptyTuple();
+
+#[derive(Getters)]
+#[min_fields = 3]
+pub struct Pair(u8, u8);
+
+fn main() {}
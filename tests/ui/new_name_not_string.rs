@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[new_name = 5]
+struct Config {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[newtype_ops]
+pub struct Point(f64, f64);
+
+fn main() {}
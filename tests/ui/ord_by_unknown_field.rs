@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[ord_by(priority, deadline)]
+pub struct Task {
+    priority: u8,
+}
+
+fn main() {}
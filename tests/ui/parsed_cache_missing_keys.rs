@@ -0,0 +1,14 @@
+use getters::Getters;
+use std::cell::OnceCell;
+
+#[derive(Getters)]
+pub struct Config {
+    #[parsed_cache(into = "port_cache")]
+    port: String,
+    #[parsed_cache(into = "port_cache", type = "u16")]
+    backup_port: String,
+    #[skip_getter]
+    port_cache: OnceCell<u16>,
+}
+
+fn main() {}
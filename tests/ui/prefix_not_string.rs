@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getters(prefix = get_)]
+pub struct Settings {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use getters::Getters;
+
+fn get_id(id: &u64) -> u64 {
+    *id
+}
+
+#[derive(Getters)]
+pub struct Order {
+    #[project_vec = "get_id"]
+    items: Vec<u64>,
+}
+
+fn main() {}
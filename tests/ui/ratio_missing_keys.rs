@@ -0,0 +1,24 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[ratio(name = "completion", numerator = "done")]
+pub struct Missing {
+    done: u32,
+    total: u32,
+}
+
+#[derive(Getters)]
+#[ratio(name = "completion", numerator = "done", denominatr = "total")]
+pub struct Misspelled {
+    done: u32,
+    total: u32,
+}
+
+#[derive(Getters)]
+#[ratio(name = "completion", numerator = "done", denominator = "total", on_zero = "one")]
+pub struct OnZero {
+    done: u32,
+    total: u32,
+}
+
+fn main() {}
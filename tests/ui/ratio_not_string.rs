@@ -0,0 +1,10 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[ratio(name = "completion", numerator = done, denominator = "total")]
+pub struct Progress {
+    done: u32,
+    total: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Buffer {
+    #[raw_parts]
+    bytes: [u8; 4],
+}
+
+fn main() {}
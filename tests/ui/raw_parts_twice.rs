@@ -0,0 +1,11 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Buffers {
+    #[raw_parts]
+    input: Vec<u8>,
+    #[raw_parts]
+    output: Vec<u8>,
+}
+
+fn main() {}
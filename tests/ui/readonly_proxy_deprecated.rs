@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use getters::Getters;
+
+#[derive(Getters)]
+#[readonly_proxy]
+pub struct Account {
+    #[getter_deprecated_since = "2.0.0"]
+    legacy_id: u32,
+}
+
+fn main() {
+    let account = Account::new(7);
+    let _ = account.as_readonly().legacy_id();
+}
@@ -0,0 +1,16 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[readonly_proxy]
+pub struct Account {
+    #[get_mut]
+    #[set]
+    owner: String,
+}
+
+fn main() {
+    let account = Account::new("ada".to_string());
+    let proxy = account.as_readonly();
+    proxy.set_owner("grace".to_string());
+    proxy.owner_mut().clear();
+}
@@ -0,0 +1,11 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Token {
+    #[rename = "kind"]
+    type_: u8,
+}
+
+fn main() {
+    let _ = Token::new(1).type_();
+}
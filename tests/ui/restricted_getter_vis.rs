@@ -0,0 +1,24 @@
+mod outer {
+    pub mod inner {
+        use getters::Getters;
+
+        #[derive(Getters)]
+        #[getters(vis = "pub(super)")]
+        pub struct Account {
+            balance: i64,
+            #[getter_vis = "pub(crate)"]
+            id: u32,
+        }
+    }
+
+    pub fn balance(account: &inner::Account) -> i64 {
+        *account.balance()
+    }
+}
+
+fn main() {
+    // `new` follows the struct-level visibility, so it cannot be called here either.
+    let account = outer::inner::Account::new(-3, 7);
+    assert_eq!(outer::balance(&account), -3);
+    account.balance();
+}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+struct Config {
+    #[return_type = 42]
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Record {
+    #[rfc3339]
+    created: u64,
+}
+
+fn main() {}
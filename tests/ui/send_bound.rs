@@ -0,0 +1,10 @@
+use getters::Getters;
+use std::rc::Rc;
+
+#[derive(Getters)]
+#[getter_send_bound]
+pub struct Shared {
+    value: Rc<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Session {
+    #[set_if_none]
+    token: String,
+}
+
+fn main() {}
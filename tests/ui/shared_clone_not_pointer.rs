@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Cache {
+    #[shared_clone]
+    entries: Vec<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[skip_all]
+pub struct Session {
+    #[include_getter]
+    user: String,
+    token: String,
+}
+
+fn main() {
+    let session = Session::new("ada".to_string(), "secret".to_string());
+    let _ = session.user();
+    let _ = session.token();
+}
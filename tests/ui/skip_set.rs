@@ -0,0 +1,13 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getters(set_all)]
+pub struct All {
+    #[skip_set]
+    host: String,
+}
+
+fn main() {
+    let mut all = All::new(String::new());
+    all.set_host(String::from("localhost"));
+}
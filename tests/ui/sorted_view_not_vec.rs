@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Scores {
+    #[sorted_view]
+    values: [u32; 3],
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[split_getter(buffer = "data")]
+pub struct Missing {
+    data: Vec<u8>,
+    consumed: usize,
+}
+
+#[derive(Getters)]
+#[split_getter(buffer = "data", index = "consumed")]
+pub struct Unknown {
+    data: Vec<u8>,
+    consumed: usize,
+}
+
+fn main() {}
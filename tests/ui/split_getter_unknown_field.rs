@@ -0,0 +1,10 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[split_getter(buffer = "data", at = "offset")]
+pub struct Reader {
+    data: Vec<u8>,
+    consumed: usize,
+}
+
+fn main() {}
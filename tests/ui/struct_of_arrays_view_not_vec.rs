@@ -0,0 +1,10 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[struct_of_arrays_view]
+pub struct Particles {
+    positions: Vec<(f32, f32)>,
+    count: usize,
+}
+
+fn main() {}
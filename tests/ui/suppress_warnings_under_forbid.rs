@@ -0,0 +1,11 @@
+#![forbid(dead_code)]
+
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_suppress_warnings]
+pub struct Config {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use getters::Getters;
+use std::cell::Cell;
+
+#[derive(Getters)]
+#[getter_sync_bound]
+pub struct Shared {
+    value: Cell<u8>,
+}
+
+fn main() {}
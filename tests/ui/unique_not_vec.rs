@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Tags {
+    #[unique]
+    names: [String; 2],
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_safety = "unsound"]
+pub struct Raw {
+    addr: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_visibility_rule = "inherit_fields"]
+pub struct Config {
+    port: u16,
+}
+
+fn main() {}
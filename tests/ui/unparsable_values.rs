@@ -0,0 +1,41 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct ReturnType {
+    #[return_type = "Vec<"]
+    items: Vec<u8>,
+}
+
+#[derive(Getters)]
+pub struct Clamp {
+    #[clamp = "0..="]
+    level: u8,
+}
+
+#[derive(Getters)]
+#[impl_hash_by = "id name"]
+pub struct HashBy {
+    id: u32,
+    name: String,
+}
+
+#[derive(Getters)]
+#[ratio(name = "done ratio", numerator = "done", denominator = "total")]
+pub struct Ratio {
+    done: u32,
+    total: u32,
+}
+
+#[derive(Getters)]
+pub struct Logic {
+    #[getter_logic = "f("]
+    value: u8,
+}
+
+#[derive(Getters)]
+pub struct LogicCopy {
+    #[getter_logic_copy = "f("]
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use getters::Getters;
+
+#[derive(Getters)]
+#[getter_safety = "unsafe"]
+pub struct Raw {
+    addr: usize,
+}
+
+fn main() {
+    let _ = Raw::new(0).addr();
+}
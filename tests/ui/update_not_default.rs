@@ -0,0 +1,11 @@
+use getters::Getters;
+
+pub struct Handle(u32);
+
+#[derive(Getters)]
+pub struct Document {
+    #[update]
+    handle: Handle,
+}
+
+fn main() {}
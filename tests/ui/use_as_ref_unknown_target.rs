@@ -0,0 +1,9 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Entry {
+    #[use_as_ref]
+    size: u64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Profile {
+    #[use_clone]
+    #[copy]
+    age: u8,
+}
+
+fn main() {}
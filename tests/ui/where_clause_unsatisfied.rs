@@ -0,0 +1,13 @@
+use getters::Getters;
+use std::rc::Rc;
+
+#[derive(Getters)]
+#[getter_where_clause = "T: Send"]
+pub struct Wrapper<T> {
+    value: T,
+}
+
+fn main() {
+    let wrapper = Wrapper { value: Rc::new(1) };
+    let _ = wrapper.value();
+}
@@ -0,0 +1,20 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Tags {
+    #[unique]
+    names: Vec<String>,
+    #[unique]
+    ids: Vec<u32>,
+}
+
+#[test]
+fn duplicates_are_dropped_in_first_seen_order() {
+    let tags = Tags::new(
+        vec!["b".to_string(), "a".to_string(), "b".to_string()],
+        vec![3, 1, 3, 2, 1],
+    );
+    assert_eq!(tags.names_unique(), ["b", "a"]);
+    assert_eq!(tags.ids_unique(), [3, 1, 2]);
+    assert_eq!(tags.ids().len(), 5);
+}
@@ -0,0 +1,51 @@
+use getters::Getters;
+use std::fmt::Debug;
+
+#[derive(Getters)]
+pub struct Generic<T: ?Sized> {
+    len: usize,
+    data: T,
+}
+
+#[derive(Getters)]
+pub struct Bytes {
+    len: usize,
+    data: [u8],
+}
+
+#[derive(Getters)]
+pub struct Text {
+    len: usize,
+    data: str,
+}
+
+#[derive(Getters)]
+pub struct Object {
+    len: usize,
+    data: dyn Debug,
+}
+
+#[test]
+fn sized_instantiation_keeps_new() {
+    let generic = Generic::new(2, [1u8, 2]);
+    assert_eq!(*generic.len(), 2);
+    assert_eq!(generic.data(), &[1, 2]);
+}
+
+#[test]
+fn getters_work_through_unsized_coercion() {
+    let boxed: Box<Generic<[u8]>> = Box::new(Generic {
+        len: 3,
+        data: [1u8, 2, 3],
+    });
+    assert_eq!(*boxed.len(), 3);
+    assert_eq!(boxed.data(), &[1, 2, 3]);
+}
+
+#[test]
+fn non_generic_unsized_structs_still_get_getters() {
+    fn describe(bytes: &Bytes, text: &Text, object: &Object) -> usize {
+        *bytes.len() + bytes.data().len() + *text.len() + text.data().len() + *object.len()
+    }
+    let _ = describe;
+}
@@ -0,0 +1,25 @@
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Document {
+    #[update]
+    tags: Vec<String>,
+    #[update]
+    title: Option<String>,
+    #[update]
+    revision: u32,
+}
+
+#[test]
+fn fields_are_replaced_by_the_closure_result() {
+    let mut document = Document::new(vec!["draft".to_string()], None, 1);
+    document.update_tags(|mut tags| {
+        tags.push("review".to_string());
+        tags
+    });
+    document.update_title(|title| title.or(Some("Untitled".to_string())));
+    document.update_revision(|revision| revision + 1);
+    assert_eq!(document.tags(), &["draft", "review"]);
+    assert_eq!(document.title().as_deref(), Some("Untitled"));
+    assert_eq!(*document.revision(), 2);
+}
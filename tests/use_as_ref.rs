@@ -0,0 +1,49 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Entry {
+    #[use_as_ref]
+    name: String,
+    #[use_as_ref]
+    path: PathBuf,
+    #[use_as_ref]
+    chunks: Vec<u16>,
+    #[use_as_ref]
+    label: Box<str>,
+    #[use_as_ref]
+    #[return_type = "&OsStr"]
+    raw: String,
+}
+
+#[test]
+fn conventional_targets_are_inferred() {
+    let entry = Entry::new(
+        "log".to_string(),
+        PathBuf::from("/var/log"),
+        vec![1, 2],
+        "boxed".into(),
+        "raw".to_string(),
+    );
+    let name: &str = entry.name();
+    let path: &Path = entry.path();
+    let chunks: &[u16] = entry.chunks();
+    let label: &str = entry.label();
+    assert_eq!((name, label), ("log", "boxed"));
+    assert_eq!(path, Path::new("/var/log"));
+    assert_eq!(chunks, [1, 2]);
+}
+
+#[test]
+fn return_type_selects_another_target() {
+    let entry = Entry::new(
+        String::new(),
+        PathBuf::new(),
+        Vec::new(),
+        "".into(),
+        "raw".to_string(),
+    );
+    assert_eq!(entry.raw(), OsStr::new("raw"));
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use getters::Getters;
+
+#[derive(Getters)]
+pub struct Profile {
+    #[use_clone]
+    tags: Vec<String>,
+    #[use_clone]
+    #[return_type = "Arc<str>"]
+    name: String,
+}
+
+#[test]
+fn getter_returns_an_owned_clone() {
+    let profile = Profile::new(vec!["admin".to_string()], "ada".to_string());
+    let mut tags: Vec<String> = profile.tags();
+    tags.push("owner".to_string());
+    assert_eq!(profile.tags(), ["admin"]);
+}
+
+#[test]
+fn clone_is_converted_into_the_return_type() {
+    let profile = Profile::new(Vec::new(), "ada".to_string());
+    let name: Arc<str> = profile.name();
+    assert_eq!(&*name, "ada");
+}
@@ -0,0 +1,38 @@
+use getters::Getters;
+
+fn non_empty(value: &str) -> bool {
+    !value.is_empty()
+}
+
+fn non_zero(value: &u16) -> bool {
+    *value != 0
+}
+
+#[derive(Debug, Getters)]
+pub struct Config {
+    #[validate = "non_empty"]
+    host: String,
+    #[validate = "non_zero"]
+    port: u16,
+}
+
+#[test]
+fn valid_values_construct() {
+    let config = Config::new("localhost".to_string(), 80);
+    assert_eq!(*config.port(), 80);
+    assert!(Config::try_new("localhost".to_string(), 80).is_ok());
+}
+
+#[test]
+fn try_new_names_the_first_invalid_field() {
+    let error = Config::try_new(String::new(), 0).unwrap_err();
+    assert_eq!(error, "field `host` failed validation `non_empty`");
+    let error = Config::try_new("localhost".to_string(), 0).unwrap_err();
+    assert_eq!(error, "field `port` failed validation `non_zero`");
+}
+
+#[test]
+#[should_panic(expected = "field `port` failed validation `non_zero`")]
+fn new_panics_on_invalid_values() {
+    Config::new("localhost".to_string(), 0);
+}
@@ -0,0 +1,48 @@
+use getters::Getters;
+
+mod inner {
+    use getters::Getters;
+
+    #[derive(Getters)]
+    #[getter_visibility_rule = "inherit_field"]
+    pub struct Fields {
+        pub open: u8,
+        pub(crate) crate_only: u8,
+        hidden: u8,
+    }
+
+    #[derive(Getters)]
+    #[getter_visibility_rule = "inherit_struct"]
+    pub(crate) struct CrateStruct {
+        value: u8,
+    }
+
+    impl Fields {
+        pub fn hidden_via_getter(&self) -> u8 {
+            *self.hidden()
+        }
+    }
+}
+
+#[derive(Getters)]
+#[getter_visibility_rule = "always_pub"]
+pub struct Always {
+    value: u8,
+}
+
+#[test]
+fn inherit_field_mirrors_field_visibility() {
+    let fields = inner::Fields::new(1, 2, 3);
+    assert_eq!((*fields.open(), *fields.crate_only()), (1, 2));
+    assert_eq!(fields.hidden_via_getter(), 3);
+}
+
+#[test]
+fn inherit_struct_mirrors_struct_visibility() {
+    assert_eq!(*inner::CrateStruct::new(4).value(), 4);
+}
+
+#[test]
+fn always_pub_is_the_default() {
+    assert_eq!(*Always::new(5).value(), 5);
+}
@@ -0,0 +1,41 @@
+use getters::Getters;
+
+#[derive(Getters, Default)]
+#[skip_new]
+pub struct Wide {
+    #[copy]
+    a: u8,
+    #[copy]
+    b: u16,
+    #[copy]
+    c: u32,
+    #[copy]
+    d: u64,
+    #[clone]
+    e: String,
+    #[clone]
+    f: String,
+    #[clone]
+    g: Vec<u8>,
+    #[clone]
+    h: Vec<u8>,
+}
+
+#[test]
+fn identical_attributes_keep_each_field_type() {
+    let wide = Wide {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+        e: "e".to_string(),
+        f: "f".to_string(),
+        g: vec![7],
+        h: vec![8],
+    };
+    let (a, b, c, d): (u8, u16, u32, u64) = (wide.a(), wide.b(), wide.c(), wide.d());
+    assert_eq!((a, b, c, d), (1, 2, 3, 4));
+    assert_eq!((wide.e(), wide.f()), ("e".to_string(), "f".to_string()));
+    assert_eq!((wide.g(), wide.h()), (vec![7], vec![8]));
+    assert_eq!(Wide::default().d(), 0);
+}